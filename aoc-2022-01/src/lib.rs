@@ -0,0 +1,87 @@
+use aoc_common::{parse_field, Answer, ParseError, Solution};
+
+/// Calories carried by every elf, largest first; elves are separated by blank lines.
+fn parse_totals(file_content: &str) -> Result<Vec<i32>, ParseError> {
+    let mut totals: Vec<i32> = vec![];
+    let mut sum = None;
+    for line in file_content.lines() {
+        if line.trim().is_empty() {
+            totals.extend(sum.take());
+        } else {
+            let v: i32 = parse_field(line.trim(), "calories")?;
+            let total = sum.unwrap_or(0i32).checked_add(v);
+            sum =
+                Some(total.ok_or_else(|| ParseError::expected("calories that fit in i32", line))?);
+        }
+    }
+    totals.extend(sum);
+    if totals.is_empty() {
+        return Err(ParseError::new("no calories"));
+    }
+
+    totals.sort();
+    totals.reverse();
+    Ok(totals)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_totals(input)
+    }
+
+    fn part1(totals: &Self::Input) -> Answer {
+        totals[0].into()
+    }
+
+    fn part2(totals: &Self::Input) -> Answer {
+        totals.iter().take(3).sum::<i32>().into()
+    }
+}
+
+/// Random valid inputs for `aoc gen`
+#[cfg(feature = "generate")]
+pub mod generate {
+    use proptest::prelude::*;
+
+    /// Up to `size` elves, each carrying up to `size` items.
+    pub fn input(size: usize) -> impl Strategy<Value = String> {
+        let elf = prop::collection::vec(1..100_000u32, 1..=size);
+        prop::collection::vec(elf, 1..=size).prop_map(|elves| {
+            elves
+                .iter()
+                .map(|items| {
+                    let items: Vec<_> = items.iter().map(|c| c.to_string()).collect();
+                    items.join("\n")
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 24000, 45000);
+        check_example::<Day>("1000\n\n2000\n3000\n", 5000, 6000);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "1000\nabc\n\n2000";
+        let e = Day::parse(input).unwrap_err().locate(input);
+        assert_eq!((e.line, e.snippet.as_deref()), (Some(2), Some("abc")));
+        assert!(Day::parse("\n").is_err());
+        let input = "1000\n2147483647\n";
+        let e = Day::parse(input).unwrap_err().locate(input);
+        assert_eq!(e.line, Some(2));
+    }
+}
@@ -0,0 +1,160 @@
+use aoc_common::{parse_lines, Answer, ParseError, Solution};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Clone)]
+enum Symbol {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+#[derive(Debug)]
+pub struct Round {
+    oponent: Symbol,
+    outcome: Outcome,
+}
+
+fn letter_to_symbol(letter: &str) -> Option<Symbol> {
+    match letter {
+        "A" => Some(Symbol::Rock),
+        "B" => Some(Symbol::Paper),
+        "C" => Some(Symbol::Scissors),
+        _ => None,
+    }
+}
+
+fn letter_to_outcome(letter: &str) -> Option<Outcome> {
+    match letter {
+        "X" => Some(Outcome::Lose),
+        "Y" => Some(Outcome::Draw),
+        "Z" => Some(Outcome::Win),
+        _ => None,
+    }
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (oponent, outcome) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::expected("two letters separated by space", s))?;
+        let oponent = letter_to_symbol(oponent)
+            .ok_or_else(|| ParseError::expected("one of A, B, C", oponent))?;
+        let outcome = letter_to_outcome(outcome)
+            .ok_or_else(|| ParseError::expected("one of X, Y, Z", outcome))?;
+        Ok(Round { oponent, outcome })
+    }
+}
+
+impl Round {
+    fn get_my_symbol(&self) -> Symbol {
+        if self.outcome == Outcome::Draw {
+            self.oponent.clone()
+        } else if self.outcome == Outcome::Lose {
+            if self.oponent == Symbol::Rock {
+                Symbol::Scissors
+            } else if self.oponent == Symbol::Paper {
+                Symbol::Rock
+            } else {
+                Symbol::Paper
+            }
+        } else if self.oponent == Symbol::Rock {
+            Symbol::Paper
+        } else if self.oponent == Symbol::Paper {
+            Symbol::Scissors
+        } else {
+            Symbol::Rock
+        }
+    }
+
+    /// Part 1 reading of the second column: X, Y, Z is the symbol to play
+    fn get_second_column_symbol(&self) -> Symbol {
+        match self.outcome {
+            Outcome::Lose => Symbol::Rock,
+            Outcome::Draw => Symbol::Paper,
+            Outcome::Win => Symbol::Scissors,
+        }
+    }
+
+    fn score(&self, my: Symbol) -> i32 {
+        let mut s = 0;
+        match my {
+            Symbol::Rock => s += 1,
+            Symbol::Paper => s += 2,
+            Symbol::Scissors => s += 3,
+        }
+        if (my == Symbol::Rock && self.oponent == Symbol::Scissors)
+            || (my == Symbol::Scissors && self.oponent == Symbol::Paper)
+            || (my == Symbol::Paper && self.oponent == Symbol::Rock)
+        {
+            s += 6;
+        } else if my == self.oponent {
+            s += 3;
+        }
+        s
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(rounds: &Self::Input) -> Answer {
+        rounds
+            .iter()
+            .map(|r| r.score(r.get_second_column_symbol()))
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part2(rounds: &Self::Input) -> Answer {
+        rounds
+            .iter()
+            .map(|r| r.score(r.get_my_symbol()))
+            .sum::<i32>()
+            .into()
+    }
+}
+
+/// Random valid inputs for `aoc gen`
+#[cfg(feature = "generate")]
+pub mod generate {
+    use proptest::prelude::*;
+
+    /// Up to `size` rounds.
+    pub fn input(size: usize) -> impl Strategy<Value = String> {
+        let round = (
+            prop::sample::select(&['A', 'B', 'C'][..]),
+            prop::sample::select(&['X', 'Y', 'Z'][..]),
+        );
+        prop::collection::vec(round, 1..=size).prop_map(|rounds| {
+            rounds
+                .iter()
+                .map(|(oponent, outcome)| format!("{} {}\n", oponent, outcome))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 15, 12);
+    }
+}
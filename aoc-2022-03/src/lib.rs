@@ -0,0 +1,91 @@
+use aoc_common::{Answer, ParseError, Solution};
+use std::collections::HashSet;
+
+fn group_packs<T, I>(mut i: I) -> Vec<[T; 3]>
+where
+    I: Iterator<Item = T>,
+{
+    let mut groups: Vec<[T; 3]> = vec![];
+
+    loop {
+        let a = i.next();
+        let b = i.next();
+        let c = i.next();
+
+        if a.is_none() || b.is_none() || c.is_none() {
+            break;
+        }
+
+        groups.push([a.unwrap(), b.unwrap(), c.unwrap()]);
+    }
+
+    groups
+}
+
+fn item_type_to_priority(v: char) -> u32 {
+    if v.is_lowercase() {
+        (v as u32) - 96
+    } else {
+        (v as u32) - 64 + 26
+    }
+}
+
+fn compartments_priority(pack: &str) -> u32 {
+    let (a, b) = pack.split_at(pack.len() / 2);
+    let a: HashSet<char> = HashSet::from_iter(a.chars());
+    b.chars()
+        .find(|c| a.contains(c))
+        .map(item_type_to_priority)
+        .unwrap_or(0)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input
+            .split('\n')
+            .filter(|&s| !s.is_empty())
+            .map(|s| s.to_string())
+            .collect())
+    }
+
+    fn part1(packs: &Self::Input) -> Answer {
+        packs
+            .iter()
+            .map(|p| compartments_priority(p))
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(packs: &Self::Input) -> Answer {
+        let packs_iter = packs
+            .iter()
+            .map(|s| -> HashSet<char> { HashSet::from_iter(s.chars()) });
+
+        let groups: Vec<[HashSet<char>; 3]> = group_packs(packs_iter);
+
+        groups
+            .iter()
+            .filter_map(|[a, b, c]| {
+                let i: HashSet<char> = HashSet::from_iter(a.intersection(b).cloned());
+                i.intersection(c).cloned().next()
+            })
+            .map(item_type_to_priority)
+            .sum::<u32>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 157, 70);
+    }
+}
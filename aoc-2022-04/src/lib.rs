@@ -0,0 +1,101 @@
+use aoc_common::{parse_field, parse_lines, Answer, ParseError, Solution};
+use aoc_interval::IntervalSet;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Range(IntervalSet<u32>);
+
+#[derive(Debug)]
+pub struct Pair(Range, Range);
+
+impl Range {
+    fn contains(&self, other: &Range) -> bool {
+        other.0.is_subset(&self.0)
+    }
+
+    fn overlap(&self, other: &Range) -> bool {
+        !self.0.is_disjoint(&other.0)
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (from, to) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::expected("range like 2-4", s))?;
+        let from = parse_field(from, "number")?;
+        let end = parse_field::<u32>(to, "number")?
+            .checked_add(1)
+            .ok_or_else(|| ParseError::expected("number that fits in u32", to))?;
+        Ok(Range(IntervalSet::from(from..end)))
+    }
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, second) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::expected("two ranges separated by comma", s))?;
+        Ok(Pair(first.parse()?, second.parse()?))
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        pairs
+            .iter()
+            .filter(|Pair(first, second)| first.contains(second) || second.contains(first))
+            .count()
+            .into()
+    }
+
+    fn part2(pairs: &Self::Input) -> Answer {
+        pairs
+            .iter()
+            .filter(|Pair(first, second)| first.overlap(second))
+            .count()
+            .into()
+    }
+}
+
+/// Random valid inputs for `aoc gen`
+#[cfg(feature = "generate")]
+pub mod generate {
+    use proptest::prelude::*;
+
+    /// Up to `size` pairs of sections numbered up to `10 × size`.
+    pub fn input(size: usize) -> impl Strategy<Value = String> {
+        let sections = 10 * size as u32;
+        let range =
+            move || (1..=sections, 0..sections).prop_map(|(start, len)| (start, start + len));
+        prop::collection::vec((range(), range()), 1..=size).prop_map(|pairs| {
+            pairs
+                .iter()
+                .map(|((a, b), (c, d))| format!("{}-{},{}-{}\n", a, b, c, d))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 2, 4);
+    }
+}
@@ -0,0 +1,133 @@
+use aoc_common::{debug, parse_field, parse_lines, Answer, ParseError, Solution};
+use std::str::FromStr;
+
+fn log_stacks(stacks: &Stacks) {
+    for stack in stacks.iter() {
+        debug!("{:?}", stack)
+    }
+}
+
+type Stacks = [Vec<char>; 9];
+
+#[derive(Debug)]
+pub struct Instruction {
+    repeat: usize,
+    from: usize,
+    to: usize,
+}
+
+fn parse_stack(s: &str) -> Result<usize, ParseError> {
+    match parse_field::<usize>(s, "stack number 1-9")? {
+        n @ 1..=9 => Ok(n - 1),
+        _ => Err(ParseError::expected("stack number 1-9", s)),
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let vals: Vec<_> = s.split(' ').collect();
+        if vals.len() != 6 {
+            return Err(ParseError::expected("move N from A to B", s));
+        }
+
+        let repeat = parse_field(vals[1], "number")?;
+        let from = parse_stack(vals[3])?;
+        let to = parse_stack(vals[5])?;
+        Ok(Instruction { repeat, from, to })
+    }
+}
+
+pub struct Crates {
+    stacks: Stacks,
+    instructions: Vec<Instruction>,
+}
+
+fn parse_crates(file_content: &str) -> Result<Crates, ParseError> {
+    let mut stacks: Stacks = Default::default();
+
+    let header = file_content
+        .split('\n')
+        .take_while(|&line| !line.is_empty())
+        .take_while(|line| line.chars().filter(|c| c.is_ascii_digit()).count() == 0);
+
+    for line in header {
+        let row = line
+            .replace("    ", " ")
+            .replace('[', "")
+            .replace("] ", "")
+            .replace(']', "");
+        let crates = row.chars().enumerate().filter(|(_, c)| !c.is_whitespace());
+        for (i, c) in crates {
+            stacks
+                .get_mut(i)
+                .ok_or_else(|| ParseError::expected("at most 9 stacks", line))?
+                .push(c);
+        }
+    }
+    for stack in stacks.iter_mut() {
+        stack.reverse();
+    }
+
+    let (_, instructions) = file_content
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::new("missing blank line between stacks and instructions"))?;
+    let instructions = parse_lines(instructions)?;
+
+    Ok(Crates {
+        stacks,
+        instructions,
+    })
+}
+
+fn rearrange(crates: &Crates, keep_order: bool) -> String {
+    let mut stacks = crates.stacks.clone();
+    log_stacks(&stacks);
+
+    for i in crates.instructions.iter() {
+        debug!("{:?}", i);
+        let mut batch: Vec<_> = vec![];
+        for _ in 0..i.repeat {
+            let c = stacks[i.from].pop().unwrap();
+            batch.push(c)
+        }
+        if keep_order {
+            stacks[i.to].extend(batch.iter().rev());
+        } else {
+            stacks[i.to].extend(batch.iter());
+        }
+        log_stacks(&stacks);
+    }
+
+    stacks.iter().filter_map(|s| s.last()).collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Crates;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_crates(input)
+    }
+
+    fn part1(crates: &Self::Input) -> Answer {
+        rearrange(crates, false).into()
+    }
+
+    fn part2(crates: &Self::Input) -> Answer {
+        rearrange(crates, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), "CMZ", "MCD");
+    }
+}
@@ -0,0 +1,42 @@
+use aoc_common::{Answer, ParseError, Solution};
+use std::collections::HashSet;
+
+fn find_marker(signal: &[char], n: usize) -> Option<usize> {
+    signal.windows(n).enumerate().find_map(|(i, window)| {
+        let chunk: HashSet<char> = window.iter().cloned().collect();
+        if chunk.len() >= n {
+            Some(i + n)
+        } else {
+            None
+        }
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.trim().chars().collect())
+    }
+
+    fn part1(signal: &Self::Input) -> Answer {
+        find_marker(signal, 4).unwrap().into()
+    }
+
+    fn part2(signal: &Self::Input) -> Answer {
+        find_marker(signal, 14).unwrap().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 7, 19);
+    }
+}
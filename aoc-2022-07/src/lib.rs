@@ -1,3 +1,4 @@
+use aoc_common::{parse_field, parse_lines, Answer, ParseError, Solution};
 use std::{cell::RefCell, rc::Rc, rc::Weak, str::FromStr};
 
 #[derive(Debug)]
@@ -7,12 +8,14 @@ enum Command {
     CdOut,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 struct File {
     name: String,
     size: usize,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 struct Dir {
     name: String,
@@ -30,11 +33,9 @@ enum ParsedLine {
     Content(Content),
 }
 
+#[allow(dead_code)]
 #[derive(Debug)]
-struct ParseError;
-
-#[derive(Debug)]
-struct Node {
+pub struct Node {
     name: String,
     parent: Weak<Node>,
     files: RefCell<Vec<File>>,
@@ -49,11 +50,10 @@ impl FromStr for Command {
             Ok(Command::Ls)
         } else if s == "$ cd .." {
             Ok(Command::CdOut)
-        } else if s.starts_with("$ cd ") {
-            let name = s.split(" ").take(3).last().ok_or(ParseError)?.into();
-            Ok(Command::CdIn { name })
+        } else if let Some(name) = s.strip_prefix("$ cd ") {
+            Ok(Command::CdIn { name: name.into() })
         } else {
-            Err(ParseError)
+            Err(ParseError::expected("$ ls or $ cd", s))
         }
     }
 }
@@ -61,12 +61,13 @@ impl FromStr for Command {
 impl FromStr for Content {
     type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.starts_with("dir ") {
-            let name = s.split_once(" ").ok_or(ParseError)?.1.into();
-            Ok(Content::Dir(Dir { name }))
+        if let Some(name) = s.strip_prefix("dir ") {
+            Ok(Content::Dir(Dir { name: name.into() }))
         } else {
-            let (size_str, name) = s.split_once(" ").ok_or(ParseError)?;
-            let size = size_str.parse::<usize>().ok().ok_or(ParseError)?;
+            let (size_str, name) = s
+                .split_once(' ')
+                .ok_or_else(|| ParseError::expected("dir or file listing", s))?;
+            let size = parse_field(size_str, "file size")?;
             Ok(Content::File(File {
                 name: name.to_string(),
                 size,
@@ -112,22 +113,25 @@ impl Iterator for NodeIter {
             self.node_stack
                 .extend(item.children.borrow().iter().rev().cloned());
         }
-        return maybe_item;
+        maybe_item
     }
 }
 
-fn main() {
-    let arg = std::env::args().skip(1).next().unwrap();
-    let file_content = std::fs::read_to_string(&arg).unwrap();
-
-    let input = file_content.split('\n').filter(|&s| !s.is_empty()).skip(1);
+fn build_tree(file_content: &str) -> Result<Rc<Node>, ParseError> {
+    let input = parse_lines::<ParsedLine>(file_content)?;
     let mut commands: Vec<(Command, Vec<File>, Vec<Dir>)> = vec![];
-    for line in input {
-        let parsed_line = line.parse::<ParsedLine>().unwrap();
+    for parsed_line in input.into_iter().skip(1) {
         match parsed_line {
             ParsedLine::Command(command) => commands.push((command, vec![], vec![])),
-            ParsedLine::Content(Content::File(file)) => commands.last_mut().unwrap().1.push(file),
-            ParsedLine::Content(Content::Dir(dir)) => commands.last_mut().unwrap().2.push(dir),
+            ParsedLine::Content(content) => {
+                let (_, files, dirs) = commands
+                    .last_mut()
+                    .ok_or_else(|| ParseError::new("listing before the first command"))?;
+                match content {
+                    Content::File(file) => files.push(file),
+                    Content::Dir(dir) => dirs.push(dir),
+                }
+            }
         }
     }
 
@@ -175,23 +179,62 @@ fn main() {
                 current_node = Rc::downgrade(&child_node);
             }
             (Command::CdOut, _, _) => {
-                current_node = current_node.upgrade().unwrap().parent.clone();
+                let parent = current_node.upgrade().unwrap().parent.clone();
+                if parent.upgrade().is_none() {
+                    return Err(ParseError::new("cd .. out of the root directory"));
+                }
+                current_node = parent;
             }
         }
     }
 
-    let available: usize = 70000000;
-    let used = dir_size(tree.as_ref());
-    let required: usize = 30000000;
-    let unused = available - used;
-    let space_needed = required - unused;
+    Ok(tree)
+}
+
+fn dir_sizes(tree: &Rc<Node>) -> impl Iterator<Item = usize> {
     let node_iter = NodeIter {
         node_stack: vec![tree.clone()],
     };
-    let result: usize = node_iter
-        .map(|node| dir_size(node.as_ref()))
-        .filter(|&node_size| node_size >= space_needed)
-        .min()
-        .unwrap();
-    println!("{}", result)
+    node_iter.map(|node| dir_size(node.as_ref()))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Rc<Node>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        build_tree(input)
+    }
+
+    fn part1(tree: &Self::Input) -> Answer {
+        dir_sizes(tree)
+            .filter(|&node_size| node_size <= 100000)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(tree: &Self::Input) -> Answer {
+        let available: usize = 70000000;
+        let used = dir_size(tree.as_ref());
+        let required: usize = 30000000;
+        let unused = available - used;
+        let space_needed = required - unused;
+        dir_sizes(tree)
+            .filter(|&node_size| node_size >= space_needed)
+            .min()
+            .unwrap()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 95437, 24933642);
+    }
 }
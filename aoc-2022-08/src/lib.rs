@@ -0,0 +1,58 @@
+use aoc_common::{Answer, ParseError, Solution};
+use aoc_grid::{Grid, Pos, DIRS4};
+use itertools::Itertools;
+
+type Map = Grid<u32>;
+
+fn is_visible(map: &Map, pos: Pos) -> bool {
+    DIRS4
+        .iter()
+        .any(|&d| map.ray(pos, d).all(|p| map[p] < map[pos]))
+}
+
+fn scenic_score(map: &Map, pos: Pos) -> usize {
+    DIRS4
+        .iter()
+        .map(|&d| {
+            map.ray(pos, d)
+                .take_while_inclusive(|&p| map[p] < map[pos])
+                .count()
+        })
+        .product()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_with(input, |c| c.to_digit(10))
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        map.positions()
+            .filter(|&pos| is_visible(map, pos))
+            .count()
+            .into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        map.positions()
+            .map(|pos| scenic_score(map, pos))
+            .max()
+            .unwrap()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 21, 8);
+    }
+}
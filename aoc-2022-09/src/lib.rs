@@ -0,0 +1,89 @@
+use aoc_common::{parse_field, Answer, ParseError, Solution};
+use aoc_grid::{Dir4, Point2};
+use std::collections::HashSet;
+
+fn move_tail(head: Point2, tail: Point2) -> Point2 {
+    if head.chebyshev(tail) <= 1 {
+        // no need to adjust anything
+        tail
+    } else {
+        // move tail towards head
+        tail + (head - tail).signum()
+    }
+}
+
+/// Direction of a move and how many steps it takes
+type Instruction = (Dir4, usize);
+
+fn parse_line(line: &str) -> Result<Instruction, ParseError> {
+    let (direction, count) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::expected("DIRECTION STEPS", line))?;
+    let direction = match direction {
+        "U" => Dir4::Up,
+        "D" => Dir4::Down,
+        "L" => Dir4::Left,
+        "R" => Dir4::Right,
+        _ => return Err(ParseError::expected("U, D, L or R", direction)),
+    };
+    Ok((direction, parse_field(count, "number of steps")?))
+}
+
+fn count_tail_positions(instructions: &[Instruction], knots: usize) -> usize {
+    let head_positions = instructions
+        .iter()
+        .flat_map(|&(direction, count)| std::iter::repeat_n(direction, count))
+        .scan(Point2::ORIGIN, |position, direction| {
+            *position += direction;
+            Some(*position)
+        });
+
+    let tail_positions = head_positions
+        .scan(vec![Point2::ORIGIN; knots], |rope, head_position| {
+            let mut new_state: Vec<Point2> = Vec::new();
+            new_state.push(move_tail(head_position, rope[0]));
+            rope.iter().skip(1).for_each(|&tail| {
+                let head = *new_state.last().unwrap();
+                new_state.push(move_tail(head, tail));
+            });
+            *rope = new_state.clone();
+            Some(new_state)
+        })
+        .map(|rope| *rope.last().unwrap());
+
+    tail_positions.collect::<HashSet<_>>().len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split('\n')
+            .filter(|s| !s.is_empty())
+            .map(parse_line)
+            .collect()
+    }
+
+    fn part1(instructions: &Self::Input) -> Answer {
+        count_tail_positions(instructions, 1).into()
+    }
+
+    fn part2(instructions: &Self::Input) -> Answer {
+        count_tail_positions(instructions, 9).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{check_example, check_part2};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 13, 1);
+        check_part2::<Day>(include_str!("../example2.txt"), 36);
+    }
+}
@@ -0,0 +1,104 @@
+use aoc_common::{parse_field, parse_lines, Answer, ParseError, Solution};
+use std::{collections::HashSet, str::FromStr};
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Noop,
+    Add(i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "noop" {
+            Ok(Instruction::Noop)
+        } else if let Some(v) = s.strip_prefix("addx ") {
+            Ok(Instruction::Add(parse_field(v, "number")?))
+        } else {
+            Err(ParseError::expected("noop or addx", s))
+        }
+    }
+}
+
+fn register_values(instructions: &[Instruction]) -> impl Iterator<Item = (usize, i32)> + '_ {
+    instructions
+        .iter()
+        .flat_map(|i| match i {
+            Instruction::Noop => vec![i.clone()],
+            Instruction::Add(_) => vec![Instruction::Noop, i.clone()],
+        })
+        .scan(1, |x, instruction| {
+            let before = *x;
+            match instruction {
+                Instruction::Noop => {}
+                Instruction::Add(v) => *x += v,
+            }
+            Some(before)
+        })
+        .enumerate()
+        .map(|(i, x)| (i + 1, x))
+}
+
+fn draw_crt(instructions: &[Instruction]) -> String {
+    let mut screen = String::new();
+    for (i, x) in register_values(instructions) {
+        let pos = (i as i32 - 1) % 40;
+        screen.push(if (pos - x).abs() <= 1 { '█' } else { ' ' });
+        if i % 40 == 0 {
+            screen.push('\n');
+        }
+    }
+    screen.trim_end_matches('\n').to_string()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Answer {
+        let cycles = HashSet::from([20, 60, 100, 140, 180, 220]);
+        register_values(instructions)
+            .filter(|(i, _)| cycles.contains(i))
+            .map(|(i, x)| (i as i32) * x)
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part2(instructions: &Self::Input) -> Answer {
+        draw_crt(instructions).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{assert_snapshot, check_example, parse_example};
+
+    #[test]
+    fn test_example() {
+        let crt = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        check_example::<Day>(
+            include_str!("../example.txt"),
+            13140,
+            crt.replace('#', "█").replace('.', " "),
+        );
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let instructions = parse_example::<Day>(include_str!("../example.txt"));
+        assert_snapshot!("crt", draw_crt(&instructions));
+    }
+}
@@ -0,0 +1,169 @@
+use aoc_common::{debug, parse_blocks, parse_field, scan::Template, Answer, ParseError, Solution};
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Old,
+    Value(i64),
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<i64>,
+    counter: i32,
+    operation: Operation,
+    operand: Operand,
+    test_modulo: i64,
+    on_true: usize,
+    on_false: usize,
+}
+
+impl Monkey {
+    fn process(&mut self, item: i64, relief: i64, modulo: i64) -> (usize, i64) {
+        self.counter += 1;
+        let operand = match self.operand {
+            Operand::Old => item,
+            Operand::Value(v) => v,
+        };
+        let mut worry_level = match self.operation {
+            Operation::Multiply => item * operand,
+            Operation::Add => item + operand,
+        };
+        worry_level = worry_level / relief % modulo;
+        if worry_level % self.test_modulo == 0 {
+            (self.on_true, worry_level)
+        } else {
+            (self.on_false, worry_level)
+        }
+    }
+
+    fn process_all(&mut self, relief: i64, modulo: i64) -> HashMap<usize, Vec<i64>> {
+        let mut result: HashMap<usize, Vec<i64>> = HashMap::new();
+        for item in self.items.clone() {
+            let (to, processed_item) = self.process(item, relief, modulo);
+            result.entry(to).or_default().push(processed_item);
+        }
+
+        result
+    }
+}
+
+const MONKEY: Template = Template::new(
+    "Monkey {}:
+  Starting items: {}
+  Operation: new = old {} {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}",
+);
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = MONKEY.scan(s.trim())?;
+        let items = fields
+            .skip(1)
+            .text()
+            .split(", ")
+            .map(|i| parse_field(i, "worry level"))
+            .collect::<Result<Vec<i64>, _>>()?;
+        let operation = match fields.text() {
+            "*" => Operation::Multiply,
+            "+" => Operation::Add,
+            other => return Err(ParseError::expected("* or +", other)),
+        };
+        let operand = match fields.text() {
+            "old" => Operand::Old,
+            other => Operand::Value(parse_field(other, "number or old")?),
+        };
+        let test_modulo = fields.parse("number")?;
+        let on_true = fields.parse("monkey number")?;
+        let on_false = fields.parse("monkey number")?;
+
+        Ok(Monkey {
+            items,
+            counter: 0,
+            operation,
+            operand,
+            test_modulo,
+            on_true,
+            on_false,
+        })
+    }
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: usize, relief: i64) -> i64 {
+    let mut monkeys = monkeys.to_vec();
+    // with relief the worry levels stay small and reducing them would break the division
+    let modulo: i64 = if relief == 1 {
+        monkeys.iter().map(|m| m.test_modulo).product()
+    } else {
+        i64::MAX
+    };
+
+    for round in 1..=rounds {
+        debug!("round {}", round);
+        for m in 0..monkeys.len() {
+            let process_result = monkeys[m].process_all(relief, modulo);
+
+            monkeys[m].items.clear();
+            for (to_monkey, items) in process_result {
+                monkeys[to_monkey].items.extend(items.iter());
+            }
+        }
+
+        for (m, monkey) in monkeys.iter().enumerate() {
+            debug!("{}: {:?} {}", m, monkey.items, monkey.counter);
+        }
+    }
+
+    let mut counters: Vec<i32> = monkeys.iter().map(|m| m.counter).collect();
+    counters.sort();
+    counters.iter().rev().take(2).map(|v| *v as i64).product()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let monkeys: Vec<Monkey> = parse_blocks(input)?;
+        if let Some(m) = monkeys
+            .iter()
+            .find(|m| m.on_true.max(m.on_false) >= monkeys.len())
+        {
+            return Err(ParseError::new(format!(
+                "throw to monkey {} out of {}",
+                m.on_true.max(m.on_false),
+                monkeys.len()
+            )));
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Answer {
+        monkey_business(monkeys, 20, 3).into()
+    }
+
+    fn part2(monkeys: &Self::Input) -> Answer {
+        monkey_business(monkeys, 10_000, 1).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 10605, 2713310158i64);
+    }
+}
@@ -0,0 +1,181 @@
+use aoc_common::{Answer, ParseError, Solution};
+use aoc_image::{gradient, Image, BLACK, GREEN, RED, WHITE};
+use aoc_search::bfs;
+use itertools::Itertools;
+use std::{collections::HashSet, str::FromStr};
+
+type Coord = (usize, usize);
+
+#[derive(Debug)]
+pub struct Map {
+    heights: Vec<Vec<u8>>,
+    start: Coord,
+    end: Coord,
+    connections: HashSet<(Coord, Coord)>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut start = None;
+        let mut end = None;
+        let heights: Vec<Vec<u8>> = s
+            .split('\n')
+            .filter(|l| !l.is_empty())
+            .enumerate()
+            .map(|(row, l)| {
+                l.char_indices()
+                    .enumerate()
+                    .map(|(col, (i, c))| match c {
+                        'S' => {
+                            start = Some((row, col));
+                            Ok(0)
+                        }
+                        'E' => {
+                            end = Some((row, col));
+                            Ok(b'z' - b'a')
+                        }
+                        'a'..='z' => Ok((c as u8) - b'a'),
+                        _ => Err(ParseError::expected(
+                            "height a-z, S or E",
+                            &l[i..i + c.len_utf8()],
+                        )),
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        let start = start.ok_or_else(|| ParseError::new("missing start S"))?;
+        let end = end.ok_or_else(|| ParseError::new("missing end E"))?;
+        if heights.is_empty() || heights.iter().any(|r| r.len() != heights[0].len()) {
+            return Err(ParseError::new("rows of the height map differ in length"));
+        }
+
+        let mut connections: HashSet<(Coord, Coord)> = HashSet::new();
+
+        for row in 0..heights.len() {
+            for col in 0..heights[0].len() {
+                if row > 0 && ((heights[row - 1][col] as i32 - heights[row][col] as i32) <= 1) {
+                    connections.insert(((row, col), (row - 1, col)));
+                }
+                if row < heights.len() - 1
+                    && ((heights[row + 1][col] as i32 - heights[row][col] as i32) <= 1)
+                {
+                    connections.insert(((row, col), (row + 1, col)));
+                }
+                if col > 0 && ((heights[row][col - 1] as i32 - heights[row][col] as i32) <= 1) {
+                    connections.insert(((row, col), (row, col - 1)));
+                }
+                if col < heights[0].len() - 1
+                    && ((heights[row][col + 1] as i32 - heights[row][col] as i32) <= 1)
+                {
+                    connections.insert(((row, col), (row, col + 1)));
+                }
+            }
+        }
+
+        Ok(Map {
+            heights,
+            start,
+            end,
+            connections,
+        })
+    }
+}
+
+impl Map {
+    fn get_neighbours(&self, u: &Coord) -> Vec<Coord> {
+        let mut candidates: Vec<Coord> = vec![(u.0 + 1, u.1), (u.0, u.1 + 1)];
+        if u.0 > 0 {
+            candidates.push((u.0 - 1, u.1))
+        }
+        if u.1 > 0 {
+            candidates.push((u.0, u.1 - 1))
+        }
+
+        candidates
+            .iter()
+            .filter(|&c| self.connections.contains(&(*u, *c)))
+            .cloned()
+            .collect()
+    }
+
+    fn shortest_path(&self, starts: impl IntoIterator<Item = Coord>) -> Option<usize> {
+        let search = bfs(starts, |u| self.get_neighbours(u), |&u| u == self.end);
+        aoc_image::capture(|| self.picture(&search.path().unwrap_or_default()));
+        search.goal_cost()
+    }
+
+    /// Heights from green valleys to white peaks, with the route in red
+    fn picture(&self, route: &[Coord]) -> Image {
+        let mut image = Image::new(self.heights[0].len(), self.heights.len(), BLACK);
+        for (r, row) in self.heights.iter().enumerate() {
+            for (c, &height) in row.iter().enumerate() {
+                image.set((r, c), gradient(GREEN, WHITE, height as f64 / 25.0));
+            }
+        }
+        for &pos in route {
+            image.set(pos, RED);
+        }
+        image
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        map.shortest_path([map.start]).unwrap().into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        let candidate_starts = (0..map.heights.len())
+            .cartesian_product(0..map.heights[0].len())
+            .filter(|(r, c)| map.heights[*r][*c] == 0);
+
+        map.shortest_path(candidate_starts).unwrap().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{check_example, parse_example};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 31, 29);
+    }
+
+    #[test]
+    fn test_picture() {
+        let map = parse_example::<Day>(include_str!("../example.txt"));
+        let route = bfs([map.start], |u| map.get_neighbours(u), |&u| u == map.end)
+            .path()
+            .unwrap();
+        let path = std::env::temp_dir().join(format!("aoc-2022-12-{}.gif", std::process::id()));
+        aoc_image::write_gif(&path, &[map.picture(&[]), map.picture(&route)], 1, 500).unwrap();
+        let frames = aoc_image::read_gif(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!((frames[0].width(), frames[0].height()), (8, 5));
+        // a at the start, z at the end
+        assert_eq!(frames[0].get(map.start), GREEN);
+        assert_eq!(frames[0].get(map.end), WHITE);
+        let red = map
+            .heights
+            .iter()
+            .enumerate()
+            .flat_map(|(r, row)| (0..row.len()).map(move |c| (r, c)))
+            .filter(|&pos| frames[1].get(pos) == RED)
+            .count();
+        assert_eq!(red, 32);
+    }
+}
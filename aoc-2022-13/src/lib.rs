@@ -1,3 +1,4 @@
+use aoc_common::{Answer, ParseError, Solution};
 use std::cmp::Ordering;
 
 use itertools::{EitherOrBoth, Itertools};
@@ -5,17 +6,12 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(untagged)]
-enum Packet {
+pub enum Packet {
     Number(i32),
     Array(Vec<Packet>),
 }
 
-struct Pair {
-    left: Vec<Packet>,
-    right: Vec<Packet>,
-}
-
-fn compare(left: &Vec<Packet>, right: &Vec<Packet>) -> Option<bool> {
+fn compare(left: &[Packet], right: &[Packet]) -> Option<bool> {
     for pair in left.iter().zip_longest(right.iter()) {
         match pair {
             // Compare the first value of each list, then the second value, and so on.
@@ -65,68 +61,73 @@ fn compare(left: &Vec<Packet>, right: &Vec<Packet>) -> Option<bool> {
             }
         }
     }
-    return None;
+    None
 }
 
-/*fn main() {
-    let arg = std::env::args().skip(1).next().unwrap();
-    let file_content = std::fs::read_to_string(arg).unwrap();
-
-    let pairs = file_content.split("\n\n").filter_map(|p| {
-        p.split_once('\n').and_then(|(left, right)| {
-            Some(Pair {
-                left: serde_json::from_str::<Vec<Packet>>(left).unwrap(),
-                right: serde_json::from_str::<Vec<Packet>>(right).unwrap(),
-            })
-        })
-    });
-
-    let result: usize = pairs
-        .enumerate()
-        .filter_map(|(i, pair)| {
-            compare(&pair.left, &pair.right).and_then(|r| if r { Some(i + 1) } else { None })
-        })
-        .sum();
-    println!("{:?}", result);
-}*/
-
-fn main() {
-    let arg = std::env::args().skip(1).next().unwrap();
-    let file_content = std::fs::read_to_string(arg).unwrap();
-
-    let mut packets: Vec<_> = file_content
-        .split('\n')
-        .filter(|line| !line.is_empty())
-        .filter_map(|line| serde_json::from_str::<Vec<Packet>>(line).ok())
-        .collect();
+pub struct Day;
 
-    let sep1 = vec![Packet::Array(vec![Packet::Number(2)])];
-    let sep2 = vec![Packet::Array(vec![Packet::Number(6)])];
+impl Solution for Day {
+    type Input = Vec<Vec<Packet>>;
 
-    packets.push(sep1.clone());
-    packets.push(sep2.clone());
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split('\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                serde_json::from_str::<Vec<Packet>>(line)
+                    .map_err(|e| ParseError::new(format!("invalid packet {}: {}", line, e)))
+            })
+            .collect()
+    }
 
-    packets.sort_by(|first, second| match compare(first, second) {
-        Some(true) => Ordering::Less,
-        Some(false) => Ordering::Greater,
-        None => Ordering::Equal,
-    });
+    fn part1(packets: &Self::Input) -> Answer {
+        packets
+            .chunks(2)
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                compare(&pair[0], &pair[1]).and_then(|r| if r { Some(i + 1) } else { None })
+            })
+            .sum::<usize>()
+            .into()
+    }
 
-    let pos1 = packets
-        .iter()
-        .find_position(|&p| compare(p, &sep1).is_none())
-        .and_then(|(i, _)| Some(i + 1))
-        .unwrap();
+    fn part2(packets: &Self::Input) -> Answer {
+        let mut packets = packets.clone();
+        let sep1 = vec![Packet::Array(vec![Packet::Number(2)])];
+        let sep2 = vec![Packet::Array(vec![Packet::Number(6)])];
+
+        packets.push(sep1.clone());
+        packets.push(sep2.clone());
+
+        packets.sort_by(|first, second| match compare(first, second) {
+            Some(true) => Ordering::Less,
+            Some(false) => Ordering::Greater,
+            None => Ordering::Equal,
+        });
+
+        let pos1 = packets
+            .iter()
+            .find_position(|&p| compare(p, &sep1).is_none())
+            .map(|(i, _)| i + 1)
+            .unwrap();
+
+        let pos2 = packets
+            .iter()
+            .find_position(|&p| compare(p, &sep2).is_none())
+            .map(|(i, _)| i + 1)
+            .unwrap();
+
+        (pos1 * pos2).into()
+    }
+}
 
-    let pos2 = packets
-        .iter()
-        .find_position(|&p| compare(p, &sep2).is_none())
-        .and_then(|(i, _)| Some(i + 1))
-        .unwrap();
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
 
-    for p in packets {
-        println!("{:?}", p);
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 13, 140);
     }
-
-    println!("{} * {} = {}", pos1, pos2, pos1 * pos2);
 }
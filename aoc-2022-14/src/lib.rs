@@ -0,0 +1,267 @@
+use aoc_common::anim::{self, Color, Frame};
+use aoc_common::{debug, parse_field, Answer, ParseError, Solution};
+use aoc_grid::{Grid, Tile};
+use aoc_image::{Image, BLACK, GREY, YELLOW};
+use itertools::Itertools;
+
+#[derive(Debug)]
+pub struct Coord {
+    col: usize,
+    row: usize,
+}
+
+#[derive(Debug)]
+struct Bounds {
+    min_col: usize,
+    max_col: usize,
+    max_row: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum WorldItem {
+    Air,
+    Stone,
+    Sand,
+}
+
+impl Tile for WorldItem {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '·' => Some(WorldItem::Air),
+            'o' => Some(WorldItem::Sand),
+            '█' => Some(WorldItem::Stone),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            WorldItem::Air => '·',
+            WorldItem::Sand => 'o',
+            WorldItem::Stone => '█',
+        }
+    }
+}
+
+/// Grid of the cave; its first column is `min_col`
+struct World {
+    grid: Grid<WorldItem>,
+    min_col: usize,
+}
+
+impl World {
+    fn get(&self, coord: &Coord) -> &WorldItem {
+        &self.grid[(coord.row, coord.col - self.min_col)]
+    }
+
+    fn set(&mut self, coord: &Coord, item: WorldItem) {
+        self.grid[(coord.row, coord.col - self.min_col)] = item;
+    }
+}
+
+fn read_input(s: &str) -> Result<Vec<Vec<Coord>>, ParseError> {
+    let lines = s.split('\n').filter(|line| !line.is_empty());
+    lines
+        .map(|line| {
+            line.split(" -> ")
+                .map(|item| {
+                    let (col, row) = item
+                        .split_once(',')
+                        .ok_or_else(|| ParseError::expected("col,row", item))?;
+                    Ok(Coord {
+                        col: parse_field(col, "column")?,
+                        row: parse_field(row, "row")?,
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn get_bounds(instructions: &[Vec<Coord>]) -> Bounds {
+    let mut min_col = usize::MAX;
+    let mut max_col = 0;
+    let mut max_row = 0;
+    for i in instructions.iter().flat_map(|line| line.iter()) {
+        if i.row > max_row {
+            max_row = i.row;
+        }
+        if i.col > max_col {
+            max_col = i.col;
+        }
+        if i.col < min_col {
+            min_col = i.col;
+        }
+    }
+
+    Bounds {
+        min_col,
+        max_col,
+        max_row,
+    }
+}
+
+fn draw_line(world: &mut World, from: &Coord, to: &Coord) {
+    if from.col == to.col {
+        let start = from.row.min(to.row);
+        let end = from.row.max(to.row);
+        for row in start..=end {
+            world.set(&Coord { col: from.col, row }, WorldItem::Stone);
+        }
+    } else if from.row == to.row {
+        let start = from.col.min(to.col);
+        let end = from.col.max(to.col);
+        for col in start..=end {
+            world.set(&Coord { col, row: from.row }, WorldItem::Stone);
+        }
+    }
+}
+
+fn create_world(instructions: &[Vec<Coord>], with_floor: bool) -> World {
+    let bounds = get_bounds(instructions);
+    let col_padding = 2 * (bounds.max_col - bounds.min_col);
+    let width = bounds.max_col - bounds.min_col + 2 * col_padding + 1;
+    let mut world = World {
+        grid: Grid::new(width, bounds.max_row + 3, WorldItem::Air),
+        min_col: bounds.min_col - col_padding,
+    };
+
+    for line in instructions {
+        for (from, to) in line.iter().tuple_windows() {
+            draw_line(&mut world, from, to);
+        }
+    }
+    if with_floor {
+        draw_line(
+            &mut world,
+            &Coord {
+                col: bounds.min_col - col_padding,
+                row: bounds.max_row + 2,
+            },
+            &Coord {
+                col: bounds.max_col + col_padding,
+                row: bounds.max_row + 2,
+            },
+        );
+    }
+    world
+}
+
+fn fall(world: &World, start: Coord) -> Option<Coord> {
+    if world.get(&start) != &WorldItem::Air {
+        return None;
+    }
+
+    let air = WorldItem::Air;
+    let crash = world
+        .grid
+        .column(start.col - world.min_col)
+        .enumerate()
+        .find(|(index, item)| index >= &start.row && *item != &air);
+
+    if let Some(crash) = crash {
+        let crash_coord = Coord {
+            col: start.col,
+            row: crash.0 - 1,
+        };
+
+        // check lower left
+        let lower_left = Coord {
+            col: crash_coord.col - 1,
+            row: crash_coord.row + 1,
+        };
+        if world.get(&lower_left) == &air {
+            return fall(world, lower_left);
+        }
+
+        // check lower right
+        let lower_right = Coord {
+            col: crash_coord.col + 1,
+            row: crash_coord.row + 1,
+        };
+        if world.get(&lower_right) == &air {
+            return fall(world, lower_right);
+        }
+
+        return Some(crash_coord);
+    }
+
+    None
+}
+
+fn picture(world: &World) -> Image {
+    Image::from_grid(&world.grid, |item| match item {
+        WorldItem::Air => BLACK,
+        WorldItem::Stone => GREY,
+        WorldItem::Sand => YELLOW,
+    })
+}
+
+/// Pours sand until no more comes to rest and returns how many units did
+fn fill(world: &mut World) -> usize {
+    for counter in 0.. {
+        let crash = fall(world, Coord { col: 500, row: 0 });
+        if let Some(crash) = crash {
+            world.set(&crash, WorldItem::Sand);
+            anim::frame(|| {
+                let (row, col) = (crash.row, crash.col - world.min_col);
+                Frame::from_text(
+                    format!("{} units of sand", counter + 1),
+                    &world.grid.to_string(),
+                )
+                .color_char('█', Color::Grey)
+                .color_char('o', Color::Yellow)
+                .color(row, col, Color::Red)
+                .focus(row, col)
+            });
+            aoc_image::capture(|| picture(world));
+        } else {
+            debug!("{}", world.grid);
+            return counter;
+        }
+    }
+    unreachable!()
+}
+
+fn pour_sand(instructions: &[Vec<Coord>], with_floor: bool) -> usize {
+    fill(&mut create_world(instructions, with_floor))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<Coord>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Answer {
+        pour_sand(instructions, false).into()
+    }
+
+    fn part2(instructions: &Self::Input) -> Answer {
+        pour_sand(instructions, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{assert_snapshot, check_example, parse_example};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 24, 93);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let instructions = parse_example::<Day>(include_str!("../example.txt"));
+        for (name, with_floor) in [("sand", false), ("sand_with_floor", true)] {
+            let mut world = create_world(&instructions, with_floor);
+            fill(&mut world);
+            assert_snapshot!(name, world.grid.to_string());
+        }
+    }
+}
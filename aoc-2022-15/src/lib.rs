@@ -0,0 +1,303 @@
+use aoc_common::{
+    assume::Assumption, checkpoint::Checkpoint, parallel, parse_lines, scan::Template, Answer,
+    ParseError, Solution,
+};
+use aoc_interval::IntervalSet;
+use itertools::Itertools;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+#[derive(Debug)]
+struct Coord {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug)]
+pub struct SensorBeacon {
+    sensor: Coord,
+    beacon: Coord,
+}
+
+const SENSOR: Template = Template::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+
+impl FromStr for SensorBeacon {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = SENSOR.scan(s.trim_end())?;
+        let sensor = Coord {
+            x: fields.parse("number")?,
+            y: fields.parse("number")?,
+        };
+        let beacon = Coord {
+            x: fields.parse("number")?,
+            y: fields.parse("number")?,
+        };
+        Ok(SensorBeacon { sensor, beacon })
+    }
+}
+
+/// In i64, as the distance between two i32 coordinates may not fit in an i32.
+fn manhattan_distance(first: &Coord, second: &Coord) -> i64 {
+    first.x.abs_diff(second.x) as i64 + first.y.abs_diff(second.y) as i64
+}
+
+fn intersect(sensor_beacon: &SensorBeacon, y: i32) -> Option<RangeInclusive<i64>> {
+    let distance = manhattan_distance(&sensor_beacon.sensor, &sensor_beacon.beacon);
+    let (x, dy) = (
+        sensor_beacon.sensor.x as i64,
+        y.abs_diff(sensor_beacon.sensor.y) as i64,
+    );
+    (dy <= distance).then(|| x - distance + dy..=x + distance - dy)
+}
+
+fn covered(y: i32, sensors_and_beacons: &[SensorBeacon]) -> IntervalSet<i64> {
+    sensors_and_beacons
+        .iter()
+        .filter_map(|sb| intersect(sb, y))
+        .collect()
+}
+
+fn process_row(y: i32, sensors_and_beacons: &[SensorBeacon], max_x: i32) -> Option<Coord> {
+    let gaps = IntervalSet::from(0..=max_x as i64).difference(&covered(y, sensors_and_beacons));
+    gaps.min().map(|x| Coord { x: x as i32, y })
+}
+
+fn count_excluded(y: i32, sensors_and_beacons: &[SensorBeacon]) -> usize {
+    let covered = covered(y, sensors_and_beacons);
+    let beacons = sensors_and_beacons
+        .iter()
+        .filter(|sb| sb.beacon.y == y && covered.contains(sb.beacon.x as i64))
+        .map(|sb| sb.beacon.x)
+        .unique()
+        .count();
+    covered.len() as usize - beacons
+}
+
+/// Rows scanned in parallel before looking for the gap among them
+const BLOCK: i32 = 10_000;
+
+fn tuning_frequency(sensors_and_beacons: &[SensorBeacon], max: i32) -> Option<i64> {
+    // the next row to scan
+    let (mut checkpoint, resumed) = Checkpoint::start("row scan");
+    let gap = (resumed.unwrap_or(0)..=max)
+        .step_by(BLOCK as usize)
+        .find_map(|start| {
+            checkpoint.save(&start);
+            let rows = start..=max.min(start.saturating_add(BLOCK - 1));
+            parallel::map(rows, |y| process_row(y, sensors_and_beacons, max))
+                .into_iter()
+                .flatten()
+                .next()
+        });
+    checkpoint.finish();
+    gap.map(|coord| coord.x as i64 * 4_000_000 + coord.y as i64)
+}
+
+/// Uncovered positions of the search area where the borders just outside of two sensor ranges
+/// cross each other or the edge of the area, and its corners; a gap of a single position lies on
+/// one of them
+fn gap_candidates(sensors_and_beacons: &[SensorBeacon], max: i32) -> Vec<(i32, i32)> {
+    let max = max as i64;
+    // the borders are the lines x + y = c and x - y = c
+    let borders = |sign: i64| -> Vec<i64> {
+        sensors_and_beacons
+            .iter()
+            .flat_map(|sb| {
+                let c = sb.sensor.x as i64 + sign * sb.sensor.y as i64;
+                let d = manhattan_distance(&sb.sensor, &sb.beacon) + 1;
+                [c - d, c + d]
+            })
+            .collect()
+    };
+    let uncovered = |coord: &Coord| {
+        sensors_and_beacons.iter().all(|sb| {
+            manhattan_distance(&sb.sensor, coord) > manhattan_distance(&sb.sensor, &sb.beacon)
+        })
+    };
+    let (sums, diffs) = (borders(1), borders(-1));
+    let crossings = sums
+        .iter()
+        .cartesian_product(&diffs)
+        .filter(|(sum, diff)| (*sum - *diff).rem_euclid(2) == 0)
+        .map(|(sum, diff)| ((sum + diff) / 2, (sum - diff) / 2));
+    let edge_crossings = sums
+        .iter()
+        .flat_map(|&sum| [(0, sum), (max, sum - max), (sum, 0), (sum - max, max)])
+        .chain(
+            diffs
+                .iter()
+                .flat_map(|&diff| [(0, -diff), (max, max - diff), (diff, 0), (diff + max, max)]),
+        );
+    let corners = [(0, 0), (0, max), (max, 0), (max, max)];
+    crossings
+        .chain(edge_crossings)
+        .chain(corners)
+        .filter(|(x, y)| (0..=max).contains(x) && (0..=max).contains(y))
+        .map(|(x, y)| Coord {
+            x: x as i32,
+            y: y as i32,
+        })
+        .filter(uncovered)
+        .map(|c| (c.x, c.y))
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+fn check_single_gap(sensors_and_beacons: &[SensorBeacon], max: i32) -> Result<(), String> {
+    match gap_candidates(sensors_and_beacons, max).len() {
+        1 => Ok(()),
+        count => Err(format!("found {} candidates", count)),
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<SensorBeacon>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(sensors_and_beacons: &Self::Input) -> Answer {
+        count_excluded(2_000_000, sensors_and_beacons).into()
+    }
+
+    fn part2(sensors_and_beacons: &Self::Input) -> Answer {
+        tuning_frequency(sensors_and_beacons, 4_000_000)
+            .unwrap()
+            .into()
+    }
+
+    fn assumptions(sensors_and_beacons: &Self::Input) -> Vec<Assumption> {
+        vec![Assumption::part2(
+            "the distress beacon is the only uncovered position between sensor ranges",
+            check_single_gap(sensors_and_beacons, 4_000_000),
+        )]
+    }
+}
+
+/// Random valid inputs, for the differential tests and `aoc gen`
+#[cfg(any(test, feature = "generate"))]
+pub mod generate {
+    use proptest::prelude::*;
+
+    /// Up to `size` sensors around a `size` × `size` square, each with its
+    /// beacon at most `size` away.
+    pub fn input(size: usize) -> impl Strategy<Value = String> {
+        let size = size as i32;
+        let coord = move || (-size..2 * size, -size..2 * size);
+        let offset = move || (-size..=size, -size..=size);
+        prop::collection::vec((coord(), offset()), 1..=size as usize).prop_map(|sensors| {
+            sensors
+                .iter()
+                .map(|((x, y), (dx, dy))| {
+                    format!(
+                        "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                        x,
+                        y,
+                        x + dx,
+                        y + dy
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::input;
+    use crate::*;
+    use aoc_common::parse_example;
+    use proptest::prelude::*;
+
+    fn in_range(sensors_and_beacons: &[SensorBeacon], position: &Coord) -> bool {
+        sensors_and_beacons.iter().any(|sb| {
+            manhattan_distance(&sb.sensor, position) <= manhattan_distance(&sb.sensor, &sb.beacon)
+        })
+    }
+
+    fn brute_force_excluded(y: i32, sensors_and_beacons: &[SensorBeacon]) -> usize {
+        let reach = |sb: &SensorBeacon| manhattan_distance(&sb.sensor, &sb.beacon);
+        let min_x = sensors_and_beacons
+            .iter()
+            .map(|sb| sb.sensor.x as i64 - reach(sb))
+            .min()
+            .unwrap();
+        let max_x = sensors_and_beacons
+            .iter()
+            .map(|sb| sb.sensor.x as i64 + reach(sb))
+            .max()
+            .unwrap();
+        (min_x..=max_x)
+            .map(|x| Coord { x: x as i32, y })
+            .filter(|c| in_range(sensors_and_beacons, c))
+            .filter(|c| {
+                !sensors_and_beacons
+                    .iter()
+                    .any(|sb| sb.beacon.x == c.x && sb.beacon.y == c.y)
+            })
+            .count()
+    }
+
+    fn brute_force_frequency(sensors_and_beacons: &[SensorBeacon], max: i32) -> Option<i64> {
+        (0..=max)
+            .flat_map(|y| (0..=max).map(move |x| Coord { x, y }))
+            .find(|c| !in_range(sensors_and_beacons, c))
+            .map(|c| c.x as i64 * 4_000_000 + c.y as i64)
+    }
+
+    #[test]
+    fn test_example() {
+        let sensors_and_beacons = parse_example::<Day>(include_str!("../example.txt"));
+        assert_eq!(count_excluded(10, &sensors_and_beacons), 26);
+        assert_eq!(tuning_frequency(&sensors_and_beacons, 20), Some(56_000_011));
+        assert_eq!(gap_candidates(&sensors_and_beacons, 20), [(14, 11)]);
+        assert!(check_single_gap(&sensors_and_beacons, 4_000_000).is_err());
+    }
+
+    #[test]
+    fn test_gap_on_edge() {
+        // covers the 5 × 5 square except its corner at (4, 4), where no two borders cross
+        let sensors_and_beacons =
+            parse_example::<Day>("Sensor at x=1, y=1: closest beacon is at x=1, y=6");
+        assert_eq!(gap_candidates(&sensors_and_beacons, 4), [(4, 4)]);
+        assert!(check_single_gap(&sensors_and_beacons, 4).is_ok());
+    }
+
+    #[test]
+    fn test_extreme_coordinates() {
+        let input = "Sensor at x=2147483647, y=0: closest beacon is at x=-2147483648, y=0";
+        let sensors_and_beacons = parse_example::<Day>(input);
+        assert_eq!(count_excluded(0, &sensors_and_beacons), 8_589_934_590);
+        assert_eq!(gap_candidates(&sensors_and_beacons, 4_000_000), []);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn prop_against_brute_force(input in input(8), y in -8..16i32, max in 0..16i32) {
+            let sensors_and_beacons = Day::parse(&input).unwrap();
+            prop_assert_eq!(
+                count_excluded(y, &sensors_and_beacons),
+                brute_force_excluded(y, &sensors_and_beacons)
+            );
+            prop_assert_eq!(
+                tuning_frequency(&sensors_and_beacons, max),
+                brute_force_frequency(&sensors_and_beacons, max)
+            );
+            let gaps: Vec<(i32, i32)> = (0..=max)
+                .cartesian_product(0..=max)
+                .filter(|&(x, y)| !in_range(&sensors_and_beacons, &Coord { x, y }))
+                .collect();
+            if gaps.len() == 1 {
+                prop_assert_eq!(gap_candidates(&sensors_and_beacons, max), gaps);
+            }
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use aoc_common::{
+    assume::Assumption,
+    checkpoint::Checkpoint,
+    log::{self, Level},
+    parse_lines,
+    scan::Template,
+    Answer, ParseError, Solution,
+};
+use aoc_search::bfs;
+use indicatif::ProgressBar;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Valve {
+    name: String,
+    flow_rate: i32,
+    tunnels: Vec<String>,
+}
+
+type Valves = Vec<Valve>;
+
+type Distances<'a> = HashMap<(&'a str, &'a str), usize>;
+
+const VALVE: Template = Template::new("Valve {} has flow rate={}; {}");
+
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = VALVE.scan(s)?;
+        let name = fields.text().to_string();
+        let flow_rate = fields.parse("flow rate")?;
+        let tunnels = fields.text();
+        let tunnels = tunnels
+            .strip_prefix("tunnels lead to valves ")
+            .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))
+            .ok_or_else(|| ParseError::expected("tunnels lead to valves", tunnels))?;
+
+        Ok(Valve {
+            name,
+            flow_rate,
+            tunnels: tunnels.split(", ").map(|t| t.to_string()).collect(),
+        })
+    }
+}
+
+fn check_symmetry(valves: &Valves) -> Result<(), String> {
+    for v in valves {
+        for dest in v.tunnels.iter() {
+            let dest_valve = valves
+                .iter()
+                .find(|v| v.name == *dest)
+                .ok_or_else(|| format!("valve {} leads to unknown valve {}", v.name, dest))?;
+            if !dest_valve.tunnels.contains(&v.name) {
+                return Err(format!("valve {} does not lead back to {}", dest, v.name));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_reachable(valves: &Valves) -> Result<(), String> {
+    let tunnels: HashMap<&str, &Vec<String>> = valves
+        .iter()
+        .map(|v| (v.name.as_str(), &v.tunnels))
+        .collect();
+    if !tunnels.contains_key("AA") {
+        return Err(String::from("there is no valve AA"));
+    }
+    let search = bfs(
+        ["AA"],
+        |&u| {
+            tunnels[u]
+                .iter()
+                .map(|t| t.as_str())
+                .filter(|t| tunnels.contains_key(t))
+        },
+        |_| false,
+    );
+    match valves
+        .iter()
+        .find(|v| v.flow_rate > 0 && !search.dist.contains_key(v.name.as_str()))
+    {
+        Some(v) => Err(format!("valve {} cannot be reached", v.name)),
+        None => Ok(()),
+    }
+}
+
+fn precompute_distances<'a>(valves: &'a Valves) -> Distances<'a> {
+    let tunnels: HashMap<&str, &Vec<String>> = valves
+        .iter()
+        .map(|v| (v.name.as_str(), &v.tunnels))
+        .collect();
+
+    let mut distances: Distances = Distances::new();
+    // from start and from each non-zero node to every other non-zero node
+    let starts = valves.iter().filter(|v| v.name == "AA" || v.flow_rate > 0);
+    for start in starts {
+        let search = bfs(
+            [start.name.as_str()],
+            |&u| tunnels[u].iter().map(|t| t.as_str()),
+            |_| false,
+        );
+        for end in valves.iter().filter(|v| v.flow_rate > 0) {
+            if start.name != end.name {
+                distances.insert((&start.name, &end.name), search.dist[end.name.as_str()]);
+            }
+        }
+    }
+    distances
+}
+
+fn simulate(route: &[&str], distances: &Distances, valves: &Valves, time: usize) -> i32 {
+    let mut released_pressure = 0;
+    let mut increase = 0;
+    let mut time_left = time;
+    let mut cur_valve = "AA";
+    let mut opened_valves: Vec<&str> = vec![];
+    let mut route_iter = route.iter();
+
+    loop {
+        let dest = route_iter.next();
+        if let Some(dest) = dest {
+            let from_to = (cur_valve, *dest);
+            let d = distances.get(&from_to).unwrap();
+            for _ in 0..=*d {
+                released_pressure += increase;
+                time_left -= 1;
+
+                if time_left == 0 {
+                    break;
+                }
+            }
+            cur_valve = dest;
+            increase += valves.iter().find(|&v| v.name == *dest).unwrap().flow_rate;
+            opened_valves.push(dest);
+        } else {
+            released_pressure += increase;
+            time_left -= 1;
+        }
+
+        if time_left == 0 {
+            break;
+        }
+    }
+
+    released_pressure
+}
+
+/// Calls `visit` with every route (including the unfinished ones) that can be walked within the
+/// time, always in the same order
+fn visit_paths<'a>(
+    cur_path: &mut Vec<&'a str>,
+    cur_distance: usize,
+    avail_nodes: &BTreeSet<&'a str>,
+    distances: &Distances,
+    time: usize,
+    visit: &mut impl FnMut(&[&'a str]),
+) {
+    if cur_distance < time {
+        for &n in avail_nodes {
+            let from = cur_path.last().copied().unwrap_or("AA");
+            let new_distance = cur_distance + distances[&(from, n)] + 1;
+            if new_distance <= time {
+                let mut new_avail_nodes = avail_nodes.clone();
+                new_avail_nodes.remove(n);
+                cur_path.push(n);
+                visit_paths(
+                    cur_path,
+                    new_distance,
+                    &new_avail_nodes,
+                    distances,
+                    time,
+                    visit,
+                );
+                cur_path.pop();
+            }
+        }
+    }
+    visit(cur_path);
+}
+
+/// Routes simulated so far, in the order of `visit_paths()`, and what they found
+#[derive(Default, Serialize, Deserialize)]
+struct Progress<T> {
+    done: usize,
+    best: T,
+}
+
+/// Simulates every route and lets `add` collect the results; an interrupted run resumes after
+/// the routes it already simulated
+fn simulate_all<T>(
+    name: &'static str,
+    valves: &Valves,
+    time: usize,
+    mut add: impl FnMut(&mut T, &[&str], i32),
+) -> T
+where
+    T: Default + Serialize + DeserializeOwned,
+{
+    let distances = precompute_distances(valves);
+    let avail_nodes = valves
+        .iter()
+        .filter(|v| v.flow_rate > 0)
+        .map(|v| v.name.as_str())
+        .collect();
+    let (mut checkpoint, resumed) = Checkpoint::start(name);
+    let mut progress: Progress<T> = resumed.unwrap_or_default();
+    let simulated = progress.done;
+    let bar = match log::enabled(Level::Warn) {
+        true => ProgressBar::new_spinner(),
+        false => ProgressBar::hidden(),
+    };
+
+    let mut index = 0;
+    visit_paths(
+        &mut vec![],
+        0,
+        &avail_nodes,
+        &distances,
+        time,
+        &mut |path| {
+            index += 1;
+            if index > simulated {
+                let pressure = simulate(path, &distances, valves, time);
+                add(&mut progress.best, path, pressure);
+                progress.done = index;
+                checkpoint.save(&progress);
+            }
+            bar.inc(1);
+        },
+    );
+    checkpoint.finish();
+    progress.best
+}
+
+fn most_pressure(valves: &Valves) -> i32 {
+    simulate_all("routes", valves, 30, |best, _, pressure| {
+        *best = pressure.max(*best)
+    })
+}
+
+/// Me and the elephant open disjoint sets of valves, so the best result is the best
+/// pair of disjoint routes
+fn most_pressure_for_two(valves: &Valves) -> i32 {
+    // keyed by the sorted names of the opened valves joined by ','
+    let best = simulate_all(
+        "routes for two",
+        valves,
+        26,
+        |best: &mut BTreeMap<String, i32>, path, pressure| {
+            let mut opened = path.to_vec();
+            opened.sort();
+            let entry = best.entry(opened.join(",")).or_default();
+            *entry = pressure.max(*entry);
+        },
+    );
+
+    let best: Vec<(HashSet<&str>, i32)> = best
+        .iter()
+        .map(|(opened, &pressure)| {
+            let opened = opened.split(',').filter(|v| !v.is_empty()).collect();
+            (opened, pressure)
+        })
+        .collect();
+
+    let mut result = 0;
+    for (i, (first, first_pressure)) in best.iter().enumerate() {
+        for (second, second_pressure) in best.iter().skip(i) {
+            if first_pressure + second_pressure > result && first.is_disjoint(second) {
+                result = first_pressure + second_pressure;
+            }
+        }
+    }
+    result
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Valves;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(valves: &Self::Input) -> Answer {
+        most_pressure(valves).into()
+    }
+
+    fn part2(valves: &Self::Input) -> Answer {
+        most_pressure_for_two(valves).into()
+    }
+
+    fn assumptions(valves: &Self::Input) -> Vec<Assumption> {
+        vec![
+            Assumption::both(
+                "tunnels lead both ways between listed valves",
+                check_symmetry(valves),
+            ),
+            Assumption::both(
+                "every valve worth opening can be reached from AA",
+                check_reachable(valves),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{check_example, parse_example};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 1651, 1707);
+    }
+
+    #[test]
+    fn test_assumptions() {
+        let valves = parse_example::<Day>(include_str!("../example.txt"));
+        assert_eq!(check_symmetry(&valves), Ok(()));
+        assert_eq!(check_reachable(&valves), Ok(()));
+
+        let valves = parse_example::<Day>(
+            "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+             Valve BB has flow rate=1; tunnel leads to valve CC\n\
+             Valve CC has flow rate=2; tunnel leads to valve CC\n",
+        );
+        assert!(check_symmetry(&valves).is_err());
+        assert_eq!(check_reachable(&valves), Ok(()));
+    }
+}
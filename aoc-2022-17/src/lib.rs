@@ -0,0 +1,272 @@
+use aoc_common::anim::{self, Color, Frame};
+use aoc_common::{cycle::skip_cycles, Answer, ParseError, Solution};
+
+#[derive(Clone)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+struct Coord {
+    x: i64,
+    y: i64,
+}
+
+struct Chamber {
+    rocks: Vec<[bool; 7]>,
+    jets: Vec<Jet>,
+    next_rock: usize,
+    next_jet: usize,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq)]
+struct StateKey {
+    pattern: [usize; 7],
+    next_rock: usize,
+    next_jet: usize,
+}
+
+impl Chamber {
+    fn new(jets: Vec<Jet>) -> Self {
+        Self {
+            rocks: Vec::new(),
+            jets,
+            next_rock: 0,
+            next_jet: 0,
+        }
+    }
+
+    fn top(&self) -> usize {
+        for y in (0..self.rocks.len()).rev() {
+            if self.rocks[y].iter().any(|&x| x) {
+                return y + 1;
+            }
+        }
+        0
+    }
+
+    fn top_pattern(&self) -> [usize; 7] {
+        let mut pattern = [usize::MAX; 7];
+        for (x, p) in pattern.iter_mut().enumerate() {
+            for (i, y) in (0..self.rocks.len()).rev().enumerate() {
+                if self.rocks[y][x] {
+                    *p = i;
+                    break;
+                }
+            }
+        }
+        pattern
+    }
+
+    fn drop_rock(&mut self) {
+        let top = self.top() as i64;
+        //println!("Top is now {}", top);
+        let mut rock: Vec<Coord> = ROCKS[self.next_rock]
+            .iter()
+            .map(|c| Coord {
+                x: c.x + 2,
+                y: c.y + top + 3,
+            })
+            .collect();
+        self.next_rock = (self.next_rock + 1) % ROCKS.len();
+
+        loop {
+            let push = &self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+            let candidate: Vec<Coord> = rock
+                .iter()
+                .map(|c| Coord {
+                    x: match push {
+                        Jet::Left => c.x - 1,
+                        Jet::Right => c.x + 1,
+                    },
+                    y: c.y,
+                })
+                .collect();
+            //println!("About to move {:?}: {:?}", push, candidate);
+            if !self.is_collision(&candidate) {
+                rock = candidate;
+            }
+            anim::frame(|| self.frame(&rock));
+
+            let candidate: Vec<Coord> = rock.iter().map(|c| Coord { x: c.x, y: c.y - 1 }).collect();
+            //println!("About to move down: {:?}", candidate);
+            if self.is_collision(&candidate) {
+                for c in rock {
+                    let y = c.y as usize;
+                    if y >= self.rocks.len() {
+                        self.rocks.resize((c.y + 1) as usize, [false; 7]);
+                    }
+                    self.rocks[y][c.x as usize] = true;
+                }
+                break;
+            } else {
+                rock = candidate;
+            }
+        }
+    }
+
+    fn state_key(&self) -> StateKey {
+        StateKey {
+            pattern: self.top_pattern(),
+            next_jet: self.next_jet,
+            next_rock: self.next_rock,
+        }
+    }
+
+    fn is_collision(&self, candidate: &[Coord]) -> bool {
+        for c in candidate {
+            if c.x >= 7 || c.x < 0 || c.y < 0 {
+                return true;
+            }
+            if (c.y as usize) < self.rocks.len() && self.rocks[c.y as usize][c.x as usize] {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Picture of the chamber as in the puzzle, with the `falling` rock drawn as `@`.
+    fn draw(&self, falling: &[Coord]) -> String {
+        let height = falling
+            .iter()
+            .map(|c| c.y as usize + 1)
+            .max()
+            .unwrap_or(0)
+            .max(self.rocks.len());
+        let mut picture = String::new();
+        for y in (0..height).rev() {
+            picture.push('|');
+            for x in 0..7 {
+                if falling.iter().any(|c| c.x == x && c.y == y as i64) {
+                    picture.push('@');
+                } else if self.rocks.get(y).is_some_and(|row| row[x as usize]) {
+                    picture.push('#');
+                } else {
+                    picture.push('.');
+                }
+            }
+            picture.push_str("|\n");
+        }
+        picture.push_str("+-------+");
+        picture
+    }
+
+    fn frame(&self, falling: &[Coord]) -> Frame {
+        Frame::from_text(format!("tower height {}", self.top()), &self.draw(falling))
+            .color_char('@', Color::Red)
+            .color_char('#', Color::Grey)
+            .focus(0, 0)
+    }
+}
+
+fn parse_jet_pattern(input: &str) -> Result<Vec<Jet>, ParseError> {
+    let pattern = input.trim_end_matches('\n');
+    if pattern.is_empty() {
+        return Err(ParseError::new("empty jet pattern"));
+    }
+    pattern
+        .char_indices()
+        .map(|(i, c)| match c {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(ParseError::expected(
+                "< or >",
+                &pattern[i..i + c.len_utf8()],
+            )),
+        })
+        .collect()
+}
+
+const ROCKS: &[&[Coord]] = &[
+    &[
+        Coord { x: 0, y: 0 },
+        Coord { x: 1, y: 0 },
+        Coord { x: 2, y: 0 },
+        Coord { x: 3, y: 0 },
+    ],
+    &[
+        Coord { x: 1, y: 0 },
+        Coord { x: 0, y: 1 },
+        Coord { x: 1, y: 1 },
+        Coord { x: 2, y: 1 },
+        Coord { x: 1, y: 2 },
+    ],
+    &[
+        Coord { x: 0, y: 0 },
+        Coord { x: 1, y: 0 },
+        Coord { x: 2, y: 0 },
+        Coord { x: 2, y: 1 },
+        Coord { x: 2, y: 2 },
+    ],
+    &[
+        Coord { x: 0, y: 0 },
+        Coord { x: 0, y: 1 },
+        Coord { x: 0, y: 2 },
+        Coord { x: 0, y: 3 },
+    ],
+    &[
+        Coord { x: 0, y: 0 },
+        Coord { x: 1, y: 0 },
+        Coord { x: 0, y: 1 },
+        Coord { x: 1, y: 1 },
+    ],
+];
+
+fn tower_height(jets: &[Jet], target_rocks_count: usize) -> usize {
+    let (_, height) = skip_cycles(
+        Chamber::new(jets.to_vec()),
+        target_rocks_count,
+        Chamber::drop_rock,
+        Chamber::state_key,
+        |chamber| chamber.top() as i64,
+    );
+    height as usize
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Jet>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_jet_pattern(input)
+    }
+
+    fn part1(jets: &Self::Input) -> Answer {
+        tower_height(jets, 2022).into()
+    }
+
+    fn part2(jets: &Self::Input) -> Answer {
+        tower_height(jets, 1_000_000_000_000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{assert_snapshot, check_example, parse_example};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 3068, 1514285714288i64);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        // the chamber after the ten rocks drawn in the puzzle
+        let mut chamber = Chamber::new(parse_example::<Day>(include_str!("../example.txt")));
+        for _ in 0..10 {
+            chamber.drop_rock();
+        }
+        assert_snapshot!("ten_rocks", chamber.draw(&[]));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "<<>x<\n";
+        let e = Day::parse(input).err().unwrap().locate(input);
+        assert_eq!((e.column, e.snippet.as_deref()), (Some(4), Some("x")));
+        assert!(Day::parse("\n").is_err());
+    }
+}
@@ -1,67 +1,8 @@
-use std::{collections::HashSet, ops::RangeInclusive, str::FromStr};
+use aoc_common::{parse_lines, Answer, ParseError, Solution};
+use aoc_grid::Point3;
+use std::{collections::HashSet, ops::RangeInclusive};
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
-struct Cube {
-    x: i32,
-    y: i32,
-    z: i32,
-}
-
-impl Cube {
-    fn neighbours(&self) -> Vec<Cube> {
-        vec![
-            Cube {
-                x: self.x + 1,
-                y: self.y,
-                z: self.z,
-            },
-            Cube {
-                x: self.x - 1,
-                y: self.y,
-                z: self.z,
-            },
-            Cube {
-                x: self.x,
-                y: self.y + 1,
-                z: self.z,
-            },
-            Cube {
-                x: self.x,
-                y: self.y - 1,
-                z: self.z,
-            },
-            Cube {
-                x: self.x,
-                y: self.y,
-                z: self.z + 1,
-            },
-            Cube {
-                x: self.x,
-                y: self.y,
-                z: self.z - 1,
-            }
-        ]
-    }
-}
-
-struct ParseCubeError;
-
-impl FromStr for Cube {
-    type Err = ParseCubeError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let items: Vec<i32> = s.trim().split(',').filter_map(|i| i.parse().ok()).collect();
-        if items.len() != 3 {
-            Err(ParseCubeError)
-        } else {
-            Ok(Self {
-                x: items[0],
-                y: items[1],
-                z: items[2],
-            })
-        }
-    }
-}
+type Cube = Point3;
 
 fn shared_faces_x(cubes: &[Cube]) -> usize {
     let mut count = 0;
@@ -119,7 +60,7 @@ fn count_shared_faces(cubes: &[Cube]) -> usize {
 }
 
 fn calc_total_area(cubes: &[Cube]) -> usize {
-    let shared_faces = count_shared_faces(&cubes[..]);
+    let shared_faces = count_shared_faces(cubes);
     cubes.len() * 6 - 2 * shared_faces
 }
 
@@ -189,27 +130,25 @@ fn transitions_z(cubes: &HashSet<Cube>) -> usize {
 fn get_neighbours(
     cube: &Cube,
     other_cubes: &HashSet<Cube>,
-    range_x: &RangeInclusive<i32>,
-    range_y: &RangeInclusive<i32>,
-    range_z: &RangeInclusive<i32>,
+    range_x: &RangeInclusive<i64>,
+    range_y: &RangeInclusive<i64>,
+    range_z: &RangeInclusive<i64>,
 ) -> Vec<Cube> {
-    cube.neighbours()
-        .iter()
+    cube.neighbours6()
         .filter(|c| {
             !other_cubes.contains(c) && range_x.contains(&c.x) && range_y.contains(&c.y) && range_z.contains(&c.z)
         })
-        .cloned()
         .collect()
 }
 
 fn flood_fill_recursive(
     seed: Cube,
     other_cubes: &mut HashSet<Cube>,
-    range_x: &RangeInclusive<i32>,
-    range_y: &RangeInclusive<i32>,
-    range_z: &RangeInclusive<i32>,
+    range_x: &RangeInclusive<i64>,
+    range_y: &RangeInclusive<i64>,
+    range_z: &RangeInclusive<i64>,
 ) {
-    other_cubes.insert(seed.clone());
+    other_cubes.insert(seed);
     let neighbours = get_neighbours(&seed, other_cubes, range_x, range_y, range_z);
     //println!("{:?} -> {:?}", seed, neighbours);
     for neighbour in neighbours {
@@ -226,9 +165,15 @@ fn flood_fill(cubes: &[Cube]) -> HashSet<Cube> {
         ..=cubes.iter().max_by_key(|cube| cube.z).unwrap().z + 1;
     //println!("x: {:?}; y: {:?}; z: {:?}", range_x, range_y, range_z);
 
+    // the corner of the enlarged bounding box is always outside of the droplet
+    let seed = Cube {
+        x: *range_x.start(),
+        y: *range_y.start(),
+        z: *range_z.start(),
+    };
     let mut other_cubes: HashSet<Cube> = cubes.iter().cloned().collect();
     flood_fill_recursive(
-        Cube { x: 0, y: 0, z: 0 },
+        seed,
         &mut other_cubes,
         &range_x,
         &range_y,
@@ -244,15 +189,97 @@ fn calc_outer_area(cubes: &[Cube]) -> usize {
     total_area - transitions
 }
 
-fn main() {
-    let arg = std::env::args().nth(1).unwrap();
-    let cubes: Vec<Cube> = std::fs::read_to_string(arg)
-        .unwrap()
-        .trim()
-        .split('\n')
-        .filter_map(|line| line.parse().ok())
-        .collect();
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Cube>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(cubes: &Self::Input) -> Answer {
+        calc_total_area(cubes).into()
+    }
+
+    fn part2(cubes: &Self::Input) -> Answer {
+        calc_outer_area(cubes).into()
+    }
+}
 
-    println!("total area: {}", calc_total_area(&cubes[..]));
-    println!("outer area: {}", calc_outer_area(&cubes[..]));
+/// Random valid inputs, for the differential tests and `aoc gen`
+#[cfg(any(test, feature = "generate"))]
+pub mod generate {
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    /// Distinct cubes in a `size` × `size` × `size` box, which is shifted
+    /// around the origin.
+    pub fn input(size: usize) -> impl Strategy<Value = String> {
+        let size = size as i32;
+        let coord = move || 0..size;
+        let cubes = prop::collection::btree_set((coord(), coord(), coord()), 1..=(size * size) as usize);
+        let shift = (-size..=size, -size..=size, -size..=size);
+        (cubes, shift).prop_map(|(cubes, (dx, dy, dz)): (BTreeSet<_>, _)| {
+            cubes
+                .iter()
+                .map(|(x, y, z)| format!("{},{},{}", x + dx, y + dy, z + dz))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::input;
+    use crate::*;
+    use aoc_common::check_example;
+    use proptest::prelude::*;
+
+    /// Faces of the cubes that touch air or, if `outer`, only air that can be
+    /// reached from outside of the bounding box.
+    fn brute_force(cubes: &[Cube], outer: bool) -> usize {
+        let cubes: HashSet<Cube> = cubes.iter().cloned().collect();
+        let min = |f: fn(&Cube) -> i64| cubes.iter().map(f).min().unwrap() - 1;
+        let max = |f: fn(&Cube) -> i64| cubes.iter().map(f).max().unwrap() + 1;
+        let (min_x, min_y, min_z) = (min(|c| c.x), min(|c| c.y), min(|c| c.z));
+        let (max_x, max_y, max_z) = (max(|c| c.x), max(|c| c.y), max(|c| c.z));
+        let inside = |c: &Cube| {
+            (min_x..=max_x).contains(&c.x)
+                && (min_y..=max_y).contains(&c.y)
+                && (min_z..=max_z).contains(&c.z)
+        };
+
+        let mut outside = HashSet::new();
+        let mut stack = vec![Cube { x: min_x, y: min_y, z: min_z }];
+        while let Some(cube) = stack.pop() {
+            if inside(&cube) && !cubes.contains(&cube) && outside.insert(cube) {
+                stack.extend(cube.neighbours6());
+            }
+        }
+
+        cubes
+            .iter()
+            .flat_map(|cube| cube.neighbours6())
+            .filter(|n| !cubes.contains(n) && (!outer || outside.contains(n)))
+            .count()
+    }
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 64, 58);
+    }
+
+    proptest! {
+        // every case floods the whole bounding box, which is slow in debug builds
+        #![proptest_config(ProptestConfig::with_cases(500))]
+
+        #[test]
+        fn prop_against_brute_force(input in input(4)) {
+            let cubes = Day::parse(&input).unwrap();
+            prop_assert_eq!(calc_total_area(&cubes), brute_force(&cubes, false));
+            prop_assert_eq!(calc_outer_area(&cubes), brute_force(&cubes, true));
+        }
+    }
 }
@@ -0,0 +1,223 @@
+use aoc_common::{info, parallel, parse_lines, scan::Template, Answer, ParseError, Solution};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Robot {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+#[derive(Debug)]
+pub struct Blueprint {
+    ores_for_ore_robot: usize,
+    ores_for_clay_robot: usize,
+    ores_for_obsidian_robot: usize,
+    clays_for_obsidian_robot: usize,
+    ores_for_geode_robot: usize,
+    obsidians_for_geode_robot: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    ores: usize,
+    clays: usize,
+    obsidians: usize,
+    geodes: usize,
+}
+
+fn harvest(robots: &[Robot], state: &mut State) {
+    for robot in robots.iter() {
+        match robot {
+            Robot::Ore => state.ores += 1,
+            Robot::Clay => state.clays += 1,
+            Robot::Obsidian => state.obsidians += 1,
+            Robot::Geode => state.geodes += 1,
+        }
+    }
+}
+
+fn can_create(robot: &Robot, state: &State, blueprint: &Blueprint) -> bool {
+    match robot {
+        Robot::Ore => state.ores >= blueprint.ores_for_ore_robot,
+        Robot::Clay => state.ores >= blueprint.ores_for_clay_robot,
+        Robot::Obsidian => {
+            state.ores >= blueprint.ores_for_obsidian_robot
+                && state.clays >= blueprint.clays_for_obsidian_robot
+        }
+        Robot::Geode => {
+            state.ores >= blueprint.ores_for_geode_robot
+                && state.obsidians >= blueprint.obsidians_for_geode_robot
+        }
+    }
+}
+
+fn assemble(state: &mut State, robot: &Robot, blueprint: &Blueprint) {
+    match robot {
+        Robot::Ore => state.ores -= blueprint.ores_for_ore_robot,
+        Robot::Clay => state.ores -= blueprint.ores_for_clay_robot,
+        Robot::Obsidian => {
+            state.ores -= blueprint.ores_for_obsidian_robot;
+            state.clays -= blueprint.clays_for_obsidian_robot;
+        }
+        Robot::Geode => {
+            state.ores -= blueprint.ores_for_geode_robot;
+            state.obsidians -= blueprint.obsidians_for_geode_robot;
+        }
+    };
+}
+
+fn count_robots(robots: &[Robot], kind: Robot) -> usize {
+    robots.iter().filter(|&r| *r == kind).count()
+}
+
+/// based on current robots return what other robots might be assembled in future
+///
+/// There is no point in having more robots of a kind than the most expensive recipe can
+/// consume in a single minute.
+fn get_strategy(robots: &[Robot], blueprint: &Blueprint) -> Vec<Robot> {
+    let max_ores = blueprint
+        .ores_for_ore_robot
+        .max(blueprint.ores_for_clay_robot)
+        .max(blueprint.ores_for_obsidian_robot)
+        .max(blueprint.ores_for_geode_robot);
+
+    let mut strategy = vec![];
+    if robots.contains(&Robot::Obsidian) {
+        strategy.push(Robot::Geode);
+    }
+    if robots.contains(&Robot::Clay)
+        && count_robots(robots, Robot::Obsidian) < blueprint.obsidians_for_geode_robot
+    {
+        strategy.push(Robot::Obsidian);
+    }
+    if count_robots(robots, Robot::Clay) < blueprint.clays_for_obsidian_robot {
+        strategy.push(Robot::Clay);
+    }
+    if count_robots(robots, Robot::Ore) < max_ores {
+        strategy.push(Robot::Ore);
+    }
+    strategy
+}
+
+fn evaluate_blueprint(
+    depth: usize,
+    max_depth: usize,
+    blueprint: &Blueprint,
+    state: State,
+    robots: Vec<Robot>,
+) -> usize {
+    let strategy = get_strategy(&robots, blueprint);
+
+    if depth == max_depth {
+        return state.geodes;
+    }
+
+    let mut max = 0;
+    for next_robot in strategy {
+        let mut next_state = state.clone();
+        let mut next_depth = depth;
+        let mut next_robots = robots.clone();
+        while !can_create(&next_robot, &next_state, blueprint) {
+            harvest(&robots, &mut next_state);
+            next_depth += 1;
+            if next_depth == max_depth {
+                break;
+            }
+        }
+
+        if next_depth < max_depth {
+            harvest(&robots, &mut next_state);
+            assemble(&mut next_state, &next_robot, blueprint);
+            next_robots.push(next_robot);
+            next_depth += 1;
+        }
+
+        let value = if next_depth < max_depth {
+            evaluate_blueprint(next_depth, max_depth, blueprint, next_state, next_robots)
+        } else {
+            next_state.geodes
+        };
+
+        if value > max {
+            max = value;
+        }
+    }
+
+    max
+}
+
+const BLUEPRINT: Template = Template::new(
+    "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+     Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+);
+
+impl FromStr for Blueprint {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = BLUEPRINT.scan(s.trim_end())?;
+        fields.skip(1);
+
+        Ok(Blueprint {
+            ores_for_ore_robot: fields.parse("cost")?,
+            ores_for_clay_robot: fields.parse("cost")?,
+            ores_for_obsidian_robot: fields.parse("cost")?,
+            clays_for_obsidian_robot: fields.parse("cost")?,
+            ores_for_geode_robot: fields.parse("cost")?,
+            obsidians_for_geode_robot: fields.parse("cost")?,
+        })
+    }
+}
+
+fn max_geodes(blueprint: &Blueprint, minutes: usize) -> usize {
+    evaluate_blueprint(0, minutes, blueprint, State::default(), vec![Robot::Ore])
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(blueprints: &Self::Input) -> Answer {
+        let geodes = parallel::map(blueprints, |blueprint| max_geodes(blueprint, 24));
+        let mut result = 0;
+        for (i, value) in geodes.into_iter().enumerate() {
+            info!("blueprint {}: {} geodes", i + 1, value);
+            result += (i + 1) * value;
+        }
+        result.into()
+    }
+
+    fn part2(blueprints: &Self::Input) -> Answer {
+        parallel::map(blueprints.iter().take(3), |blueprint| {
+            max_geodes(blueprint, 32)
+        })
+        .into_iter()
+        .product::<usize>()
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{check_part1, parse_example};
+
+    #[test]
+    fn test_example() {
+        check_part1::<Day>(include_str!("../example.txt"), 33);
+    }
+
+    #[test]
+    #[ignore = "the 32 minute search takes too long on the example blueprints"]
+    fn test_example_part2() {
+        let blueprints = parse_example::<Day>(include_str!("../example.txt"));
+        assert_eq!(max_geodes(&blueprints[0], 32), 56);
+        assert_eq!(max_geodes(&blueprints[1], 32), 62);
+    }
+}
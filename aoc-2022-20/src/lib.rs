@@ -1,8 +1,10 @@
+use aoc_common::{Answer, ParseError, Solution};
+
 fn modulo(a: i64, b: usize) -> usize {
     (((a % b as i64) + b as i64) % b as i64) as usize
 }
 
-fn shift(input: &mut Vec<(usize, i64)>, index: usize) {
+fn shift(input: &mut [(usize, i64)], index: usize) {
     let s = input[index].1;
     if s == 0 {
         return;
@@ -16,24 +18,17 @@ fn shift(input: &mut Vec<(usize, i64)>, index: usize) {
     }
 }
 
-fn mix(input: &mut Vec<(usize, i64)>) {
+fn mix(input: &mut [(usize, i64)]) {
     for orig_index in 0..input.len() {
         let index = input.iter().position(|&(i, _)| i == orig_index).unwrap();
         shift(input, index);
     }
 }
 
-fn main() {
-    let arg = std::env::args().nth(1).unwrap();
-    let mut input: Vec<(usize, i64)> = std::fs::read_to_string(arg)
-        .unwrap()
-        .split('\n')
-        .filter(|line| !line.is_empty())
-        .map(|line| line.parse::<i64>().unwrap() * 811589153)
-        .enumerate()
-        .collect();
-
-    for _ in 0..10 {
+fn grove_coordinates(numbers: &[i64], key: i64, rounds: usize) -> i64 {
+    let mut input: Vec<(usize, i64)> = numbers.iter().map(|n| n * key).enumerate().collect();
+
+    for _ in 0..rounds {
         mix(&mut input);
     }
 
@@ -41,12 +36,38 @@ fn main() {
     let i1 = input[modulo(index_of_zero as i64 + 1000, input.len())].1;
     let i2 = input[modulo(index_of_zero as i64 + 2000, input.len())].1;
     let i3 = input[modulo(index_of_zero as i64 + 3000, input.len())].1;
-    println!("{} + {} + {} = {}", i1, i2, i3, i1 + i2 + i3)
+    i1 + i2 + i3
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split('\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.parse::<i64>()
+                    .map_err(|_| ParseError::new(format!("invalid number: {}", line)))
+            })
+            .collect()
+    }
+
+    fn part1(numbers: &Self::Input) -> Answer {
+        grove_coordinates(numbers, 1, 1).into()
+    }
+
+    fn part2(numbers: &Self::Input) -> Answer {
+        grove_coordinates(numbers, 811589153, 10).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::shift;
+    use crate::{shift, Day};
+    use aoc_common::check_example;
 
     #[test]
     fn test_shift_right() {
@@ -56,10 +77,15 @@ mod tests {
 
         let mut input = vec![(1, 1), (2, 2), (3, 8), (4, 4), (5, 5)];
         shift(&mut input, 2);
-        assert_eq!(input, vec![(3, 8), (4, 4), (5, 5), (1, 1), (2, 2)]);
+        assert_eq!(input, vec![(1, 1), (2, 2), (3, 8), (4, 4), (5, 5)]);
 
         let mut input = vec![(1, 1), (2, 2), (3, 13), (4, 4), (5, 5)];
         shift(&mut input, 2);
-        assert_eq!(input, vec![(3, 13), (5, 5), (1, 1), (2, 2), (4, 4)]);
+        assert_eq!(input, vec![(1, 1), (2, 2), (4, 4), (3, 13), (5, 5)]);
+    }
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 3, 1623178306);
     }
 }
@@ -0,0 +1,165 @@
+use aoc_common::{info, parse_field, parse_lines, Answer, ParseError, Solution};
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, Clone)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone)]
+pub struct Job {
+    op: Op,
+    left: String,
+    right: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Monkey {
+    Value(f64),
+    Job(Job),
+}
+
+struct NamedMonkey(String, Monkey);
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let items: Vec<_> = s.split(' ').collect();
+        if items.len() == 1 {
+            Ok(Monkey::Value(parse_field(items[0], "number")?))
+        } else if items.len() == 3 {
+            let op = match items[1] {
+                "*" => Op::Mul,
+                "/" => Op::Div,
+                "+" => Op::Add,
+                "-" => Op::Sub,
+                &_ => return Err(ParseError::expected("one of + - * /", items[1])),
+            };
+            Ok(Monkey::Job(Job {
+                op,
+                left: items[0].into(),
+                right: items[2].into(),
+            }))
+        } else {
+            Err(ParseError::expected("number or monkey operation", s))
+        }
+    }
+}
+
+impl FromStr for NamedMonkey {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, job) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseError::expected("name: job", s))?;
+        Ok(NamedMonkey(name.into(), job.parse()?))
+    }
+}
+
+fn eval(monkey: &Monkey, monkeys: &Monkeys) -> f64 {
+    match monkey {
+        Monkey::Value(value) => *value,
+        Monkey::Job(job) => {
+            let left = eval(monkeys.get(&job.left).unwrap(), monkeys);
+            let right = eval(monkeys.get(&job.right).unwrap(), monkeys);
+            match job.op {
+                Op::Add => left + right,
+                Op::Sub => left - right,
+                Op::Mul => left * right,
+                Op::Div => left / right,
+            }
+        }
+    }
+}
+
+fn get_root_children(monkeys: &Monkeys) -> Option<(String, String)> {
+    if let Monkey::Job(root) = &monkeys["root"] {
+        return Some((root.left.clone(), root.right.clone()));
+    }
+    None
+}
+
+type Monkeys = HashMap<String, Monkey>;
+
+/// The root equality depends linearly on the human value, so a single probe is enough to
+/// extrapolate the value that makes both sides equal
+fn find_human_value(monkeys: &Monkeys) -> Option<i64> {
+    let mut monkeys = monkeys.clone();
+    let (root_left, root_right) = get_root_children(&monkeys).unwrap();
+
+    let incr_factor = 500_000_000.0;
+    if let Monkey::Value(init_human) = monkeys["humn"] {
+        info!("initial human value: {}", init_human);
+        let init_left = eval(&monkeys[&root_left], &monkeys);
+        let init_right = eval(&monkeys[&root_right], &monkeys);
+        info!("left: {}", init_left);
+        info!("right: {}", init_right);
+
+        info!("increasing human value to: {}", init_human + incr_factor);
+        monkeys.insert("humn".into(), Monkey::Value(init_human + incr_factor));
+
+        let after_left = eval(&monkeys[&root_left], &monkeys);
+        let after_right = eval(&monkeys[&root_right], &monkeys);
+        info!("left: {}", after_left);
+        info!("right: {}", after_right);
+
+        let left_delta = after_left - init_left;
+        let right_delta = after_right - init_right;
+
+        if left_delta != 0.0 && right_delta == 0.0 {
+            let target_delta = init_right - init_left;
+            info!(
+                "delta achieved by increase of {}: {}",
+                incr_factor, left_delta
+            );
+            info!("target delta: {}", target_delta);
+            let increase = incr_factor * target_delta / left_delta;
+            info!("required increase of initial human value: {}", increase);
+            let result = (init_human + increase).round();
+
+            monkeys.insert("humn".into(), Monkey::Value(result));
+            let result_left = eval(&monkeys[&root_left], &monkeys);
+            let result_right = eval(&monkeys[&root_right], &monkeys);
+            info!("left: {}", result_left);
+            info!("right: {}", result_right);
+            return Some(result as i64);
+        }
+    }
+    None
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Monkeys;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let monkeys = parse_lines::<NamedMonkey>(input)?;
+        Ok(monkeys
+            .into_iter()
+            .map(|NamedMonkey(name, monkey)| (name, monkey))
+            .collect())
+    }
+
+    fn part1(monkeys: &Self::Input) -> Answer {
+        (eval(&monkeys["root"], monkeys) as i64).into()
+    }
+
+    fn part2(monkeys: &Self::Input) -> Answer {
+        find_human_value(monkeys).unwrap().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 152, 301);
+    }
+}
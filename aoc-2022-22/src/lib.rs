@@ -1,50 +1,32 @@
+use aoc_common::{assume::Assumption, debug, parse_field, Answer, ParseError, Solution};
+use aoc_grid::Dir4;
 use std::mem::swap;
 
 #[derive(Debug, Clone, Copy)]
-enum Rotate {
-    Left = -1,
-    Right = 1,
+pub enum Rotate {
+    Left,
+    Right,
 }
 
 #[derive(Debug)]
-enum Instruction {
+pub enum Instruction {
     Rotate(Rotate),
     Move(usize),
 }
 
 #[derive(Debug, PartialEq)]
-enum Tile {
+pub enum Tile {
     Open,
     Wall,
 }
 
 type World = Vec<Vec<Option<Tile>>>;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum Orientation {
-    Right = 0,
-    Down,
-    Left,
-    Up,
-}
-
-impl From<i32> for Orientation {
-    fn from(value: i32) -> Self {
-        match value {
-            0 => Orientation::Right,
-            1 => Orientation::Down,
-            2 => Orientation::Left,
-            3 => Orientation::Up,
-            _ => panic!(),
-        }
-    }
-}
-
 #[derive(Debug, Clone)]
 struct State {
     row: usize,
     col: usize,
-    orientation: Orientation,
+    orientation: Dir4,
 }
 
 /// used as a result of `get_adjacent_side()`. Describes which side is adjacent to some other
@@ -56,7 +38,7 @@ struct AdjacentSide {
     /// side column within unfolded cube map
     c: usize,
     /// new orientation
-    dir: Orientation,
+    dir: Dir4,
     /// if row or column should be inverted (e.g. from column 40 to column 10)
     inv: bool,
     // if row and column properties of the state should be swapped
@@ -85,7 +67,7 @@ impl State {
         State {
             row,
             col,
-            orientation: Orientation::Right,
+            orientation: Dir4::Right,
         }
     }
 
@@ -101,15 +83,14 @@ impl State {
         get_next: fn(&World, &State) -> Option<State>,
     ) -> Self {
         match instruction {
-            Instruction::Rotate(rotate) => {
-                let old_orientation = self.orientation as i32;
-                let new_orientation = ((old_orientation + *rotate as i32) + 4) % 4;
-                State {
-                    row: self.row,
-                    col: self.col,
-                    orientation: new_orientation.into(),
-                }
-            }
+            Instruction::Rotate(rotate) => State {
+                row: self.row,
+                col: self.col,
+                orientation: match rotate {
+                    Rotate::Left => self.orientation.turn_left(),
+                    Rotate::Right => self.orientation.turn_right(),
+                },
+            },
             Instruction::Move(steps) => {
                 let mut state = self.clone();
                 for _ in 0..*steps {
@@ -133,10 +114,10 @@ fn get_next(world: &World, state: &State) -> Option<State> {
     let mut next_col = state.col;
     let row_len = world[state.row].len();
     match state.orientation {
-        Orientation::Up => next_row = (state.row + world.len() - 1) % world.len(),
-        Orientation::Down => next_row = (state.row + 1) % world.len(),
-        Orientation::Right => next_col = (state.col + 1) % row_len,
-        Orientation::Left => next_col = (state.col + row_len - 1) % row_len,
+        Dir4::Up => next_row = (state.row + world.len() - 1) % world.len(),
+        Dir4::Down => next_row = (state.row + 1) % world.len(),
+        Dir4::Right => next_col = (state.col + 1) % row_len,
+        Dir4::Left => next_col = (state.col + row_len - 1) % row_len,
     };
     let next = &world[next_row][next_col];
     let next_state = State {
@@ -153,22 +134,22 @@ fn get_next(world: &World, state: &State) -> Option<State> {
 
 /// Fixies initial (side relative) coordinates of the player after movement to the adjacent side of
 /// the cube.
-fn fix_initial_rc(new_state: &mut State, orientation: Orientation) {
+fn fix_initial_rc(new_state: &mut State, orientation: Dir4) {
     match orientation {
-        Orientation::Up => new_state.row = 50 - 1,
-        Orientation::Down => new_state.row = 0,
-        Orientation::Left => new_state.col = 50 - 1,
-        Orientation::Right => new_state.col = 0,
+        Dir4::Up => new_state.row = 50 - 1,
+        Dir4::Down => new_state.row = 0,
+        Dir4::Left => new_state.col = 50 - 1,
+        Dir4::Right => new_state.col = 0,
     }
 }
 
 /// Inverse side-relatie row or column coordinates based on the result of `get_adjacent_side()`
 ///
 /// see `AdjacentSide::inv`
-fn invese_rc(new_state: &mut State, orientation: Orientation) {
+fn invese_rc(new_state: &mut State, orientation: Dir4) {
     match orientation {
-        Orientation::Left | Orientation::Right => new_state.row = 50 - 1 - new_state.row,
-        Orientation::Up | Orientation::Down => new_state.col = 50 - 1 - new_state.col,
+        Dir4::Left | Dir4::Right => new_state.row = 50 - 1 - new_state.row,
+        Dir4::Up | Dir4::Down => new_state.col = 50 - 1 - new_state.col,
     };
 }
 
@@ -233,28 +214,28 @@ fn get_next_on_cube_no_wrap(
 /// When player reaches boundaries of the world, the movement continues on the adjacent cube side.
 fn get_next_on_cube(world: &World, state: &State) -> Option<State> {
     match state.orientation {
-        Orientation::Right => {
+        Dir4::Right => {
             if (state.col % 50) == 50 - 1 {
                 get_next_on_cube_wrap(world, state)
             } else {
                 get_next_on_cube_no_wrap(world, state, state.row, state.col + 1)
             }
         }
-        Orientation::Left => {
-            if (state.col % 50) == 0 {
+        Dir4::Left => {
+            if state.col.is_multiple_of(50) {
                 get_next_on_cube_wrap(world, state)
             } else {
                 get_next_on_cube_no_wrap(world, state, state.row, state.col - 1)
             }
         }
-        Orientation::Up => {
-            if (state.row % 50) == 0 {
+        Dir4::Up => {
+            if state.row.is_multiple_of(50) {
                 get_next_on_cube_wrap(world, state)
             } else {
                 get_next_on_cube_no_wrap(world, state, state.row - 1, state.col)
             }
         }
-        Orientation::Down => {
+        Dir4::Down => {
             if (state.row % 50) == 50 - 1 {
                 get_next_on_cube_wrap(world, state)
             } else {
@@ -288,189 +269,189 @@ fn get_next_on_cube(world: &World, state: &State) -> Option<State> {
 /// - we will continue on that side oriented to the right
 /// - row property should be switched (e.g from row 40 we should continue on row 10)
 /// - row and column should not be switched
-fn get_adjacent_side(side_row: usize, side_col: usize, orientation: Orientation) -> AdjacentSide {
+fn get_adjacent_side(side_row: usize, side_col: usize, orientation: Dir4) -> AdjacentSide {
     if side_row == 0 && side_col == 1 {
         match orientation {
-            Orientation::Right => AdjacentSide {
+            Dir4::Right => AdjacentSide {
                 r: 0,
                 c: 2,
-                dir: Orientation::Right,
+                dir: Dir4::Right,
                 inv: false,
                 swap_rc: false,
             },
-            Orientation::Down => AdjacentSide {
+            Dir4::Down => AdjacentSide {
                 r: 1,
                 c: 1,
-                dir: Orientation::Down,
+                dir: Dir4::Down,
                 inv: false,
                 swap_rc: false,
             },
-            Orientation::Left => AdjacentSide {
+            Dir4::Left => AdjacentSide {
                 r: 2,
                 c: 0,
-                dir: Orientation::Right,
+                dir: Dir4::Right,
                 inv: true,
                 swap_rc: false,
             },
-            Orientation::Up => AdjacentSide {
+            Dir4::Up => AdjacentSide {
                 r: 3,
                 c: 0,
-                dir: Orientation::Right,
+                dir: Dir4::Right,
                 inv: false,
                 swap_rc: true,
             },
         }
     } else if side_row == 0 && side_col == 2 {
         match orientation {
-            Orientation::Right => AdjacentSide {
+            Dir4::Right => AdjacentSide {
                 r: 2,
                 c: 1,
-                dir: Orientation::Left,
+                dir: Dir4::Left,
                 inv: true,
                 swap_rc: false,
             },
-            Orientation::Down => AdjacentSide {
+            Dir4::Down => AdjacentSide {
                 r: 1,
                 c: 1,
-                dir: Orientation::Left,
+                dir: Dir4::Left,
                 inv: false,
                 swap_rc: false,
             },
-            Orientation::Left => AdjacentSide {
+            Dir4::Left => AdjacentSide {
                 r: 0,
                 c: 1,
-                dir: Orientation::Left,
+                dir: Dir4::Left,
                 inv: false,
                 swap_rc: false,
             },
-            Orientation::Up => AdjacentSide {
+            Dir4::Up => AdjacentSide {
                 r: 3,
                 c: 0,
-                dir: Orientation::Up,
+                dir: Dir4::Up,
                 inv: false,
                 swap_rc: false,
             },
         }
     } else if side_row == 1 && side_col == 1 {
         match orientation {
-            Orientation::Right => AdjacentSide {
+            Dir4::Right => AdjacentSide {
                 r: 0,
                 c: 2,
-                dir: Orientation::Up,
+                dir: Dir4::Up,
                 inv: false,
                 swap_rc: true,
             },
-            Orientation::Down => AdjacentSide {
+            Dir4::Down => AdjacentSide {
                 r: 2,
                 c: 1,
-                dir: Orientation::Down,
+                dir: Dir4::Down,
                 inv: false,
                 swap_rc: false,
             },
-            Orientation::Left => AdjacentSide {
+            Dir4::Left => AdjacentSide {
                 r: 2,
                 c: 0,
-                dir: Orientation::Down,
+                dir: Dir4::Down,
                 inv: false,
                 swap_rc: true,
             },
-            Orientation::Up => AdjacentSide {
+            Dir4::Up => AdjacentSide {
                 r: 0,
                 c: 1,
-                dir: Orientation::Up,
+                dir: Dir4::Up,
                 inv: false,
                 swap_rc: false,
             },
         }
     } else if side_row == 2 && side_col == 0 {
         match orientation {
-            Orientation::Right => AdjacentSide {
+            Dir4::Right => AdjacentSide {
                 r: 2,
                 c: 1,
-                dir: Orientation::Right,
+                dir: Dir4::Right,
                 inv: false,
                 swap_rc: false,
             },
-            Orientation::Down => AdjacentSide {
+            Dir4::Down => AdjacentSide {
                 r: 3,
                 c: 0,
-                dir: Orientation::Down,
+                dir: Dir4::Down,
                 inv: false,
                 swap_rc: false,
             },
-            Orientation::Left => AdjacentSide {
+            Dir4::Left => AdjacentSide {
                 r: 0,
                 c: 1,
-                dir: Orientation::Right,
+                dir: Dir4::Right,
                 inv: true,
                 swap_rc: false,
             },
-            Orientation::Up => AdjacentSide {
+            Dir4::Up => AdjacentSide {
                 r: 1,
                 c: 1,
-                dir: Orientation::Right,
+                dir: Dir4::Right,
                 inv: false,
                 swap_rc: true,
             },
         }
     } else if side_row == 2 && side_col == 1 {
         match orientation {
-            Orientation::Right => AdjacentSide {
+            Dir4::Right => AdjacentSide {
                 r: 0,
                 c: 2,
-                dir: Orientation::Left,
+                dir: Dir4::Left,
                 inv: true,
                 swap_rc: false,
             },
-            Orientation::Down => AdjacentSide {
+            Dir4::Down => AdjacentSide {
                 r: 3,
                 c: 0,
-                dir: Orientation::Left,
+                dir: Dir4::Left,
                 inv: false,
                 swap_rc: true,
             },
-            Orientation::Left => AdjacentSide {
+            Dir4::Left => AdjacentSide {
                 r: 2,
                 c: 0,
-                dir: Orientation::Left,
+                dir: Dir4::Left,
                 inv: false,
                 swap_rc: false,
             },
-            Orientation::Up => AdjacentSide {
+            Dir4::Up => AdjacentSide {
                 r: 1,
                 c: 1,
-                dir: Orientation::Up,
+                dir: Dir4::Up,
                 inv: false,
                 swap_rc: false,
             },
         }
     } else if side_row == 3 && side_col == 0 {
         match orientation {
-            Orientation::Right => AdjacentSide {
+            Dir4::Right => AdjacentSide {
                 r: 2,
                 c: 1,
-                dir: Orientation::Up,
+                dir: Dir4::Up,
                 inv: false,
                 swap_rc: true,
             },
-            Orientation::Down => AdjacentSide {
+            Dir4::Down => AdjacentSide {
                 r: 0,
                 c: 2,
-                dir: Orientation::Down,
+                dir: Dir4::Down,
                 inv: false,
                 swap_rc: false,
             },
-            Orientation::Left => AdjacentSide {
+            Dir4::Left => AdjacentSide {
                 r: 0,
                 c: 1,
-                dir: Orientation::Down,
+                dir: Dir4::Down,
                 inv: false,
                 swap_rc: true,
             },
-            Orientation::Up => AdjacentSide {
+            Dir4::Up => AdjacentSide {
                 r: 2,
                 c: 0,
-                dir: Orientation::Up,
+                dir: Dir4::Up,
                 inv: false,
                 swap_rc: false,
             },
@@ -483,49 +464,77 @@ fn get_adjacent_side(side_row: usize, side_col: usize, orientation: Orientation)
     }
 }
 
-fn parse_instructions(input: &str) -> Vec<Instruction> {
-    let mut move_buffer: String = "".into();
-    let mut result = vec![];
-    for c in input.trim().chars() {
-        match c {
-            'L' => {
-                if !move_buffer.is_empty() {
-                    result.push(Instruction::Move(move_buffer.parse().unwrap()));
-                    move_buffer.clear();
-                }
-                result.push(Instruction::Rotate(Rotate::Left));
-            }
-            'R' => {
-                if !move_buffer.is_empty() {
-                    result.push(Instruction::Move(move_buffer.parse().unwrap()));
-                    move_buffer.clear();
-                }
-                result.push(Instruction::Rotate(Rotate::Right));
+/// Faces of the cube net that `get_adjacent_side()` knows, as (side row, side column)
+const CUBE_NET: [(usize, usize); 6] = [(0, 1), (0, 2), (1, 1), (2, 0), (2, 1), (3, 0)];
+
+fn check_cube_net(world: &World) -> Result<(), String> {
+    let (rows, cols) = (world.len(), world.first().map_or(0, Vec::len));
+    if (rows, cols) != (4 * 50, 3 * 50) {
+        return Err(format!("the map is {}x{} instead of 200x150", rows, cols));
+    }
+    for side_row in 0..4 {
+        for side_col in 0..3 {
+            let tiles = world[side_row * 50..][..50]
+                .iter()
+                .flat_map(|row| &row[side_col * 50..][..50])
+                .filter(|tile| tile.is_some())
+                .count();
+            let expected = match CUBE_NET.contains(&(side_row, side_col)) {
+                true => 50 * 50,
+                false => 0,
+            };
+            if tiles != expected {
+                return Err(format!(
+                    "the side at row {}, column {} has {} tiles instead of {}",
+                    side_row, side_col, tiles, expected
+                ));
             }
-            _ => move_buffer.push(c),
         }
     }
-    if !move_buffer.is_empty() {
-        result.push(Instruction::Move(move_buffer.parse().unwrap()));
-        move_buffer.clear();
+    Ok(())
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let input = input.trim();
+    let parse_move = |tiles: &str| Ok(Instruction::Move(parse_field(tiles, "number of tiles")?));
+    let mut result = vec![];
+    // start of the number of tiles to move
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        let rotate = match c {
+            'L' => Rotate::Left,
+            'R' => Rotate::Right,
+            _ => continue,
+        };
+        if start < i {
+            result.push(parse_move(&input[start..i])?);
+        }
+        result.push(Instruction::Rotate(rotate));
+        start = i + 1;
+    }
+    if start < input.len() {
+        result.push(parse_move(&input[start..])?);
     }
-    result
+    Ok(result)
 }
 
-fn parse_world(input: &str) -> World {
+fn parse_world(input: &str) -> Result<World, ParseError> {
     let mut world = vec![];
     let mut max_cols = 0;
 
     for line in input.split('\n') {
         let row: Vec<Option<Tile>> = line
-            .chars()
-            .map(|c| match c {
-                ' ' => None,
-                '.' => Some(Tile::Open),
-                '#' => Some(Tile::Wall),
-                _ => panic!(),
+            .char_indices()
+            .map(|(i, c)| match c {
+                ' ' => Ok(None),
+                '.' => Ok(Some(Tile::Open)),
+                '#' => Ok(Some(Tile::Wall)),
+                _ => Err(ParseError::expected(
+                    "open tile, wall or space",
+                    &line[i..i + c.len_utf8()],
+                )),
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         let cols = row.len();
         world.push(row);
         if cols > max_cols {
@@ -537,44 +546,92 @@ fn parse_world(input: &str) -> World {
         row.resize_with(max_cols, || None);
     }
 
-    world
+    Ok(world)
 }
 
-fn print_world(world: &World) {
+fn log_world(world: &World) {
     for row in world {
-        for tile in row {
-            let c = match tile {
-                None => 'x',
-                Some(tile) => match tile {
-                    Tile::Open => '.',
-                    Tile::Wall => '#',
-                },
-            };
-            print!("{}", c);
-        }
-        println!();
+        let tile_char = |tile: &Option<Tile>| match tile {
+            None => 'x',
+            Some(Tile::Open) => '.',
+            Some(Tile::Wall) => '#',
+        };
+        debug!("{}", row.iter().map(tile_char).collect::<String>());
+    }
+}
+
+pub struct Notes {
+    world: World,
+    instructions: Vec<Instruction>,
+}
+
+fn password(notes: &Notes, get_next: fn(&World, &State) -> Option<State>) -> usize {
+    let mut state = State::init(&notes.world);
+    for instruction in notes.instructions.iter() {
+        state = state.apply_instruction(&notes.world, instruction, get_next);
     }
+
+    let facing = match state.orientation {
+        Dir4::Right => 0,
+        Dir4::Down => 1,
+        Dir4::Left => 2,
+        Dir4::Up => 3,
+    };
+    (state.row + 1) * 1000 + 4 * (state.col + 1) + facing
 }
 
-fn main() {
-    let arg = std::env::args().nth(1).unwrap();
-    let input_file: Vec<_> = std::fs::read_to_string(arg)
-        .unwrap()
-        .split("\n\n")
-        .map(|s| s.to_string())
-        .collect();
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Notes;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (world, instructions) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new("missing blank line between map and path"))?;
 
-    let instructions = parse_instructions(input_file[1].as_str());
-    println!("{:?}", instructions);
+        let instructions = parse_instructions(instructions)?;
+        debug!("{:?}", instructions);
 
-    let world = parse_world(input_file[0].as_str());
-    print_world(&world);
+        let world = parse_world(world)?;
+        log_world(&world);
 
-    let mut state = State::init(&world);
-    for instruction in instructions {
-        state = state.apply_instruction(&world, &instruction, get_next_on_cube);
+        Ok(Notes {
+            world,
+            instructions,
+        })
     }
 
-    let result = (state.row + 1) * 1000 + 4 * (state.col + 1) + state.orientation as usize;
-    println!("Result: {}", result)
+    fn part1(notes: &Self::Input) -> Answer {
+        password(notes, get_next).into()
+    }
+
+    fn part2(notes: &Self::Input) -> Answer {
+        password(notes, get_next_on_cube).into()
+    }
+
+    fn assumptions(notes: &Self::Input) -> Vec<Assumption> {
+        vec![Assumption::part2(
+            "the map is the cube net of the real input with 50 wide sides",
+            check_cube_net(&notes.world),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{check_part1, parse_example};
+
+    #[test]
+    fn test_example() {
+        // part 2 only knows how the cube net of the real input folds
+        check_part1::<Day>(include_str!("../example.txt"), 6032);
+    }
+
+    #[test]
+    fn test_assumptions() {
+        let notes = parse_example::<Day>(include_str!("../example.txt"));
+        assert!(check_cube_net(&notes.world).is_err());
+    }
 }
@@ -0,0 +1,210 @@
+use aoc_common::anim::{self, Color, Frame};
+use aoc_common::{debug, Answer, ParseError, Solution};
+use aoc_grid::{Dir8, Grid, Point2};
+use aoc_image::{Image, BLACK, GREEN};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+struct Boundary {
+    min_r: i64,
+    min_c: i64,
+    max_r: i64,
+    max_c: i64,
+}
+
+type World = HashSet<Point2>;
+
+fn parse_world(input: &str) -> Result<World, ParseError> {
+    let mut world = World::new();
+
+    for (r, row) in input
+        .split('\n')
+        .filter(|line| !line.is_empty())
+        .enumerate()
+    {
+        for (c, (i, ch)) in row.char_indices().enumerate() {
+            match ch {
+                '#' => {
+                    world.insert(Point2::new(c as i64, r as i64));
+                }
+                '.' => {}
+                _ => return Err(ParseError::expected("# or .", &row[i..i + ch.len_utf8()])),
+            }
+        }
+    }
+    if world.is_empty() {
+        return Err(ParseError::new("no elves"));
+    }
+    Ok(world)
+}
+
+fn get_world_boundary(world: &World) -> Boundary {
+    let mut b = Boundary {
+        min_r: i64::MAX,
+        min_c: i64::MAX,
+        max_r: i64::MIN,
+        max_c: i64::MIN,
+    };
+    for coord in world.iter() {
+        b.max_r = b.max_r.max(coord.y);
+        b.max_c = b.max_c.max(coord.x);
+        b.min_r = b.min_r.min(coord.y);
+        b.min_c = b.min_c.min(coord.x);
+    }
+    b
+}
+
+fn world_grid(world: &World) -> Grid<char> {
+    let b = get_world_boundary(world);
+    let width = (b.max_c - b.min_c + 1) as usize;
+    let height = (b.max_r - b.min_r + 1) as usize;
+    let mut grid = Grid::new(width, height, '.');
+    for coord in world {
+        grid[((coord.y - b.min_r) as usize, (coord.x - b.min_c) as usize)] = '#';
+    }
+    grid
+}
+
+/// Frame of the elves after `round`, with the viewport in the middle.
+fn frame(world: &World, round: usize) -> Frame {
+    let grid = world_grid(world);
+    Frame::from_text(format!("end of round {}", round), &grid.to_string())
+        .color_char('#', Color::Green)
+        .focus(grid.height() / 2, grid.width() / 2)
+}
+
+fn picture(world: &World) -> Image {
+    Image::from_grid(
+        &world_grid(world),
+        |&c| if c == '#' { GREEN } else { BLACK },
+    )
+}
+
+fn count_empty(world: &World) -> usize {
+    let b = get_world_boundary(world);
+    let mut count = 0;
+    for r in b.min_r..=b.max_r {
+        for c in b.min_c..=b.max_c {
+            if !world.contains(&Point2::new(c, r)) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn step(world: &World, round: usize) -> (World, bool) {
+    let mut new_world = World::new();
+
+    // destination coord -> vector of source coordinates
+    let mut proposals: HashMap<Point2, Vec<Point2>> = HashMap::new();
+
+    // first half of each round
+    for source in world {
+        let destination = propose_move(world, *source, round);
+        proposals.entry(destination).or_default().push(*source);
+    }
+
+    // second half of the round:
+    // Each Elf moves to their proposed destination tile if they were the only Elf to propose moving to that position
+    // If two or more Elves propose moving to the same position, none of those Elves move.
+
+    let mut moved = false;
+    for (destination, sources) in proposals {
+        if sources.len() == 1 {
+            if sources[0] != destination {
+                //println!("Moving from {:?} to {:?}", sources[0], destination);
+                moved = true
+            }
+            new_world.insert(destination);
+        } else {
+            for src in sources {
+                new_world.insert(src);
+            }
+        }
+    }
+
+    (new_world, moved)
+}
+
+/// The directions an elf considers in turn, each with the adjacent positions
+/// that have to be free to move there.
+const CHECKS: [[Dir8; 3]; 4] = [
+    [Dir8::N, Dir8::NE, Dir8::NW],
+    [Dir8::S, Dir8::SE, Dir8::SW],
+    [Dir8::W, Dir8::NW, Dir8::SW],
+    [Dir8::E, Dir8::NE, Dir8::SE],
+];
+
+fn propose_move(world: &World, src: Point2, round: usize) -> Point2 {
+    // If no other Elves are in one of eight adjacent positions, the Elf does not do anything during this round
+    if src.neighbours8().all(|n| !world.contains(&n)) {
+        return src;
+    }
+
+    // Elf looks in each of four directions and proposes moving one step in the first valid direction
+    for check_index in round..=round + 3 {
+        let check = CHECKS[check_index % 4];
+        if check.iter().all(|&d| !world.contains(&(src + d))) {
+            return src + check[0];
+        }
+    }
+
+    src
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = World;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_world(input)
+    }
+
+    fn part1(world: &Self::Input) -> Answer {
+        let mut world = world.clone();
+        for round in 0..10 {
+            (world, _) = step(&world, round);
+            anim::frame(|| frame(&world, round + 1));
+            aoc_image::capture(|| picture(&world));
+        }
+        count_empty(&world).into()
+    }
+
+    fn part2(world: &Self::Input) -> Answer {
+        let mut world = world.clone();
+        let mut moved;
+        for round in 0.. {
+            (world, moved) = step(&world, round);
+            anim::frame(|| frame(&world, round + 1));
+            aoc_image::capture(|| picture(&world));
+            debug!("== End of Round {} ==; someone moved: {}", round + 1, moved);
+            if !moved {
+                debug!("{}", world_grid(&world));
+                return (round + 1).into();
+            }
+        }
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 110, 20);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "..#\n#o.\n";
+        let e = Day::parse(input).unwrap_err().locate(input);
+        assert_eq!((e.line, e.column), (Some(2), Some(2)));
+        assert_eq!(e.snippet.as_deref(), Some("o"));
+        assert!(Day::parse("...\n").is_err());
+    }
+}
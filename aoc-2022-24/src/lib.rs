@@ -0,0 +1,251 @@
+use aoc_common::anim::{self, Color, Frame};
+use aoc_common::{info, Answer, ParseError, Solution};
+use aoc_grid::{Dir4, Point2};
+use std::{collections::HashMap, collections::HashSet, fmt, mem::swap, str::FromStr};
+
+type Blizzards = HashMap<Point2, Vec<Dir4>>;
+
+#[derive(Clone)]
+pub struct World {
+    blizzards: Blizzards,
+    players: HashSet<Point2>,
+    start: Point2,
+    finish: Point2,
+    size: Point2,
+}
+
+impl fmt::Display for World {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..=self.size.y {
+            for x in 0..=self.size.x {
+                let coord = Point2::new(x, y);
+                if self.players.contains(&coord) {
+                    write!(f, "P")?;
+                } else if coord == self.start || coord == self.finish {
+                    write!(f, ".")?;
+                } else if self.blizzards.contains_key(&coord) {
+                    let blizzards = &self.blizzards[&coord];
+                    if blizzards.len() > 1 {
+                        write!(f, "{}", blizzards.len())?;
+                    } else {
+                        match blizzards[0] {
+                            Dir4::Left => write!(f, "<")?,
+                            Dir4::Right => write!(f, ">")?,
+                            Dir4::Up => write!(f, "^")?,
+                            Dir4::Down => write!(f, "v")?,
+                        }
+                    }
+                } else if x == 0 || y == 0 || coord.x == self.size.x || coord.y == self.size.y {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl World {
+    /// Frame following the expedition position closest to the finish.
+    fn frame(&self, minute: usize) -> Frame {
+        let mut frame = Frame::from_text(format!("minute {}", minute), &self.to_string())
+            .color_char('#', Color::Grey)
+            .color_char('P', Color::Green);
+        for c in ['<', '>', '^', 'v', '2', '3', '4'] {
+            frame = frame.color_char(c, Color::Cyan);
+        }
+        let leader = self.players.iter().min_by_key(|p| p.manhattan(self.finish));
+        match leader {
+            Some(p) => frame.focus(p.y as usize, p.x as usize),
+            None => frame,
+        }
+    }
+
+    fn move_blizzards(&self) -> Blizzards {
+        let mut result: Blizzards = HashMap::new();
+
+        for (coord, blizzards) in self.blizzards.iter() {
+            for blizzard in blizzards {
+                // move
+                let mut new_coord = *coord + *blizzard;
+
+                // wrap
+                if new_coord.x == 0 {
+                    new_coord.x = self.size.x - 1;
+                }
+                if new_coord.x == self.size.x {
+                    new_coord.x = 1;
+                }
+                if new_coord.y == 0 {
+                    new_coord.y = self.size.y - 1;
+                }
+                if new_coord.y == self.size.y {
+                    new_coord.y = 1;
+                }
+
+                // append to result
+                result.entry(new_coord).or_default().push(*blizzard);
+            }
+        }
+
+        result
+    }
+
+    fn step(self) -> (World, bool) {
+        // move all blizzards
+        let new_blizzards = self.move_blizzards();
+        let mut new_players = HashSet::new();
+        let mut finished = false;
+
+        // move each player
+        for old_player in self.players {
+            for candidate in std::iter::once(old_player).chain(old_player.neighbours4()) {
+                // skip the candidate position if it is not start or finish
+                // ..or if it is outside of the world boundaries
+                // ..or if it collides with some blizzard
+                if candidate != self.start
+                    && candidate != self.finish
+                    && (candidate.x <= 0
+                        || candidate.y <= 0
+                        || candidate.x >= self.size.x
+                        || candidate.y >= self.size.y
+                        || new_blizzards.contains_key(&candidate))
+                {
+                    continue;
+                }
+
+                if candidate == self.finish {
+                    finished = true;
+                }
+
+                new_players.insert(candidate);
+            }
+        }
+
+        (
+            World {
+                blizzards: new_blizzards,
+                players: new_players,
+                start: self.start,
+                finish: self.finish,
+                size: self.size,
+            },
+            finished,
+        )
+    }
+}
+
+impl FromStr for World {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut blizzards = Blizzards::new();
+        let blizzard_chars = ['>', '<', '^', 'v'];
+        let lines = s.split('\n').filter(|line| !line.is_empty());
+        let mut max_x = 0;
+        let mut max_y = 0;
+        for (y, line) in lines.enumerate() {
+            for (x, (i, char)) in line.char_indices().enumerate() {
+                if x > max_x {
+                    max_x = x;
+                }
+                if y > max_y {
+                    max_y = y;
+                }
+                if !blizzard_chars.contains(&char) {
+                    if char == '#' || char == '.' {
+                        continue;
+                    }
+                    return Err(ParseError::expected(
+                        "one of # . < > ^ v",
+                        &line[i..i + char.len_utf8()],
+                    ));
+                }
+                let blizzard = match char {
+                    '<' => Dir4::Left,
+                    '>' => Dir4::Right,
+                    '^' => Dir4::Up,
+                    'v' => Dir4::Down,
+                    _ => unreachable!(),
+                };
+                blizzards
+                    .entry(Point2::new(x as i64, y as i64))
+                    .or_default()
+                    .push(blizzard);
+            }
+        }
+
+        Ok(World {
+            blizzards,
+            players: HashSet::from([Point2::new(1, 0)]),
+            start: Point2::new(1, 0),
+            finish: Point2::new(max_x as i64 - 1, max_y as i64),
+            size: Point2::new(max_x as i64, max_y as i64),
+        })
+    }
+}
+
+/// Steps the world until someone reaches the finish
+fn trip(mut world: World) -> (World, usize) {
+    for step in 1.. {
+        let finished;
+        (world, finished) = world.step();
+        anim::frame(|| world.frame(step));
+        if finished {
+            info!("finished in {} steps", step);
+            return (world, step);
+        }
+    }
+    unreachable!()
+}
+
+fn turn_around(world: &mut World) {
+    world.players.clear();
+    swap(&mut world.start, &mut world.finish);
+    world.players = HashSet::from([world.start]);
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = World;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(world: &Self::Input) -> Answer {
+        trip(world.clone()).1.into()
+    }
+
+    fn part2(world: &Self::Input) -> Answer {
+        // to finish
+        let (mut world, mut total) = trip(world.clone());
+
+        // back to start
+        turn_around(&mut world);
+        let steps;
+        (world, steps) = trip(world);
+        total += steps;
+
+        // to finish again
+        turn_around(&mut world);
+        let steps;
+        (_, steps) = trip(world);
+        total += steps;
+
+        total.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 18, 54);
+    }
+}
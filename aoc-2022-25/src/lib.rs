@@ -0,0 +1,103 @@
+use aoc_common::{Answer, ParseError, Solution};
+
+type Snafu = Vec<i64>;
+
+fn parse_snafu(s: &str) -> Result<Snafu, ParseError> {
+    s.char_indices()
+        .map(|(i, char)| match char {
+            '2' => Ok(2),
+            '1' => Ok(1),
+            '0' => Ok(0),
+            '-' => Ok(-1),
+            '=' => Ok(-2),
+            c => Err(ParseError::expected("SNAFU digit", &s[i..i + c.len_utf8()])),
+        })
+        .collect()
+}
+
+fn snafu_to_decimal(snafu: &[i64]) -> i64 {
+    snafu
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(exp, value)| 5_i64.pow(exp as u32) * value)
+        .sum()
+}
+
+fn decimal_to_snafu(d: i64) -> Snafu {
+    let mut snafu = vec![];
+    let mut value = d;
+    loop {
+        let mut remainder = value % 5;
+        let mut borrow = false;
+
+        // if remainder is 3 or 4, we have to subtract instead
+        // and borrow 5 for next round
+        if remainder > 2 {
+            borrow = true;
+            remainder -= 5;
+        }
+
+        snafu.push(remainder);
+        value /= 5;
+        if borrow {
+            value += 1;
+        }
+        if value == 0 {
+            break;
+        }
+    }
+    snafu.reverse();
+    snafu
+}
+
+fn format_snafu(snafu: &Snafu) -> String {
+    snafu
+        .iter()
+        .map(|v| match v {
+            2 => '2',
+            1 => '1',
+            0 => '0',
+            -1 => '-',
+            -2 => '=',
+            c => panic!("Unknown SNAFU symbol {}", c),
+        })
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Snafu>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split('\n')
+            .filter(|line| !line.is_empty())
+            .map(parse_snafu)
+            .collect()
+    }
+
+    fn part1(numbers: &Self::Input) -> Answer {
+        let sum: i64 = numbers
+            .iter()
+            .map(|snafu_number| snafu_to_decimal(snafu_number))
+            .sum();
+        format_snafu(&decimal_to_snafu(sum)).into()
+    }
+
+    fn part2(_: &Self::Input) -> Answer {
+        Answer::Empty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), "2=-1=0", Answer::Empty);
+    }
+}
@@ -0,0 +1,91 @@
+use aoc_common::{Answer, ParseError, Solution};
+
+fn extract_numbers(s: &str) -> usize {
+    let digits: Vec<usize> = s
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|v| v as usize)
+        .collect();
+    let first = digits.first().unwrap();
+    let last = digits.last().unwrap();
+    first * 10 + last
+}
+
+fn get_numbers() -> Vec<(usize, String)> {
+    let mut digits: Vec<_> = (0..=9).map(|d| (d, d.to_string())).collect();
+    let words = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .enumerate();
+
+    digits.extend(words);
+    digits
+}
+
+fn find_from_left(s: &str) -> usize {
+    get_numbers()
+        .iter()
+        .filter_map(|(num, pat)| s.find(pat).map(|index| (index, *num)))
+        .min_by_key(|(index, _num)| *index)
+        .unwrap()
+        .1
+}
+
+fn find_from_right(s: &str) -> usize {
+    get_numbers()
+        .iter()
+        .filter_map(|(num, pat)| s.rfind(pat).map(|index| (index, *num)))
+        .max_by_key(|(index, _num)| *index)
+        .unwrap()
+        .1
+}
+
+fn extract_numbers_2(s: &str) -> usize {
+    let first = find_from_left(s);
+    let last = find_from_right(s);
+    first * 10 + last
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input
+            .split('\n')
+            .filter(|&line| !line.is_empty())
+            .map(|line| line.to_string())
+            .collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .map(|s| extract_numbers(s))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .map(|s| extract_numbers_2(s))
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{check_part1, check_part2};
+
+    #[test]
+    fn test_example() {
+        check_part1::<Day>(include_str!("../example.txt"), 142);
+        check_part2::<Day>(include_str!("../example2.txt"), 281);
+    }
+}
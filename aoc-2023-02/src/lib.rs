@@ -1,27 +1,25 @@
+use aoc_common::{parse_field, parse_lines, Answer, ParseError, Solution};
 use std::str::FromStr;
 
 #[derive(Debug)]
-struct GameParseError;
-
-#[derive(Debug)]
-struct Game {
+pub struct Game {
     id: i32,
     sets: Vec<Set>,
 }
 
 impl FromStr for Game {
-    type Err = GameParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (game, sets) = s.split_once(": ").ok_or(GameParseError)?;
+        let (game, sets) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseError::expected("Game N: sets", s))?;
         let game_id = game
-            .split_once(' ')
-            .ok_or(GameParseError)?
-            .1
-            .parse()
-            .map_err(|_| GameParseError)?;
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::expected("Game N", game))?;
+        let game_id = parse_field(game_id, "game id")?;
 
-        let sets: Result<Vec<Set>, GameParseError> = sets
+        let sets: Result<Vec<Set>, ParseError> = sets
             .split(';')
             .map(|s| s.trim())
             .map(|s| s.parse())
@@ -110,51 +108,70 @@ impl Set {
 }
 
 impl FromStr for Set {
-    type Err = GameParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut red = None;
         let mut green = None;
         let mut blue = None;
-        s.split(", ")
-            .filter_map(|s| s.trim().split_once(' '))
-            .for_each(|(count, color)| {
-                let count = count.parse().ok();
-                match color {
-                    "red" => red = count,
-                    "green" => green = count,
-                    "blue" => blue = count,
-                    _ => {}
-                }
-            });
+        for cubes in s.split(", ") {
+            let (count, color) = cubes
+                .trim()
+                .split_once(' ')
+                .ok_or_else(|| ParseError::expected("count and color", cubes))?;
+            let count = Some(parse_field(count, "cube count")?);
+            match color {
+                "red" => red = count,
+                "green" => green = count,
+                "blue" => blue = count,
+                _ => return Err(ParseError::expected("red, green or blue", color)),
+            }
+        }
 
         Ok(Set { red, green, blue })
     }
 }
 
-fn main() {
-    let games: Result<Vec<Game>, GameParseError> =
-        std::fs::read_to_string(std::env::args().nth(1).unwrap())
-            .unwrap()
-            .split('\n')
-            .filter(|line| !line.is_empty())
-            .map(|line| line.parse())
-            .collect();
-    let games = games.unwrap();
-
-    let bag = Bag {
-        red: 12,
-        green: 13,
-        blue: 14,
-    };
-
-    let result: i32 = games
-        .iter()
-        .filter(|game| game.is_plausible(&bag))
-        .map(|game| game.id)
-        .sum();
-    println!("{}", result);
-
-    let result: i32 = games.iter().map(|game| game.get_min().power()).sum();
-    println!("{}", result);
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(games: &Self::Input) -> Answer {
+        let bag = Bag {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        games
+            .iter()
+            .filter(|game| game.is_plausible(&bag))
+            .map(|game| game.id)
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part2(games: &Self::Input) -> Answer {
+        games
+            .iter()
+            .map(|game| game.get_min().power())
+            .sum::<i32>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 8, 2286);
+    }
 }
@@ -1,3 +1,4 @@
+use aoc_common::{parse_field, Answer, ParseError, Solution};
 use std::{collections::HashMap, str::FromStr};
 
 #[derive(Debug)]
@@ -38,34 +39,35 @@ impl Number {
     }
 }
 
-struct World {
+pub struct World {
     symbols: HashMap<(i32, i32), Symbol>,
     numbers: Vec<Number>,
 }
 
-#[derive(Debug)]
-struct WorldParseError;
-
 impl FromStr for World {
-    type Err = WorldParseError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let lines = s.split('\n').filter(|line| !line.is_empty());
         let mut symbols = HashMap::new();
         let mut numbers = Vec::new();
         for (row, line) in lines.enumerate() {
+            if !line.is_ascii() {
+                return Err(ParseError::expected("ASCII characters", line));
+            }
             let mut line_content = line.chars().enumerate();
             while let Some((col, c)) = line_content.next() {
                 if c == '.' {
                     // pass
                 } else if c.is_ascii_digit() {
-                    let digits: String = line[col..]
-                        .chars()
+                    let len = line[col..]
+                        .bytes()
                         .take_while(|c| c.is_ascii_digit())
-                        .collect();
+                        .count();
+                    let digits = &line[col..col + len];
                     numbers.push(Number {
                         row: row as i32,
                         col: col as i32,
-                        value: digits.parse().map_err(|_| WorldParseError)?,
+                        value: parse_field(digits, "part number")?,
                         width: digits.len() as i32,
                     });
                     // skip remaining digits in line_content iterator
@@ -85,43 +87,57 @@ impl FromStr for World {
     }
 }
 
-fn any_symbol(coords: &Vec<(i32, i32)>, symbols: &HashMap<(i32, i32), Symbol>) -> bool {
+fn any_symbol(coords: &[(i32, i32)], symbols: &HashMap<(i32, i32), Symbol>) -> bool {
     for coord in coords {
-        if symbols.get(coord).is_some() {
+        if symbols.contains_key(coord) {
             return true;
         }
     }
     false
 }
 
-fn main() {
-    let world: World = std::fs::read_to_string(std::env::args().nth(1).unwrap())
-        .unwrap()
-        .parse()
-        .unwrap();
+pub struct Day;
 
-    let sum: i32 = world
-        .numbers
-        .iter()
-        .filter(|number| any_symbol(&number.neighbourhood(), &world.symbols))
-        .map(|number| number.value)
-        .sum();
-    println!("{}", sum);
+impl Solution for Day {
+    type Input = World;
 
-    let mut gears: HashMap<(i32, i32), Vec<Number>> = HashMap::new();
-    for number in world.numbers {
-        for gear in number.get_gears(&world.symbols) {
-            if let Some(v) = gears.get_mut(&gear) {
-                v.push(number.clone())
-            } else {
-                gears.insert(gear, vec![number.clone()]);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(world: &Self::Input) -> Answer {
+        world
+            .numbers
+            .iter()
+            .filter(|number| any_symbol(&number.neighbourhood(), &world.symbols))
+            .map(|number| number.value)
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part2(world: &Self::Input) -> Answer {
+        let mut gears: HashMap<(i32, i32), Vec<Number>> = HashMap::new();
+        for number in world.numbers.iter() {
+            for gear in number.get_gears(&world.symbols) {
+                gears.entry(gear).or_default().push(number.clone());
             }
         }
+        gears
+            .values()
+            .filter(|numbers| numbers.len() == 2)
+            .map(|numbers| numbers[0].value * numbers[1].value)
+            .sum::<i32>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 4361, 467835);
     }
-    let sum: i32 = gears
-        .values()
-        .filter(|numbers| numbers.len() == 2)
-        .map(|numbers| numbers[0].value * numbers[1].value)
-        .sum();
-    println!("{}", sum);
 }
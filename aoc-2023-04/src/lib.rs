@@ -0,0 +1,97 @@
+use aoc_common::{parse_field, parse_lines, Answer, ParseError, Solution};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+#[derive(Debug)]
+pub struct Card {
+    winning: HashSet<i32>,
+    owned: HashSet<i32>,
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_card_id, numbers) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseError::expected("Card N: numbers", s))?;
+        let (winning, owned) = numbers
+            .split_once(" | ")
+            .ok_or_else(|| ParseError::expected("winning | owned numbers", numbers))?;
+        let winning: Result<HashSet<i32>, _> = winning
+            .split(' ')
+            .filter(|v| !v.is_empty())
+            .map(|n| parse_field(n, "number"))
+            .collect();
+        let owned: Result<HashSet<i32>, _> = owned
+            .split(' ')
+            .filter(|v| !v.is_empty())
+            .map(|n| parse_field(n, "number"))
+            .collect();
+
+        Ok(Card {
+            owned: owned?,
+            winning: winning?,
+        })
+    }
+}
+
+impl Card {
+    fn points(&self) -> i32 {
+        let len = self.intersection_len() as u32;
+        if len == 0 {
+            return 0;
+        }
+        2_i32.pow(len - 1)
+    }
+
+    fn intersection_len(&self) -> usize {
+        self.owned.intersection(&self.winning).count()
+    }
+}
+
+fn count_winning_scratchcards(index: usize, cards: &[Card], counter: &mut HashMap<usize, usize>) {
+    *counter.entry(index).or_default() += 1;
+
+    let intersection = cards[index].intersection_len();
+    if intersection > 0 {
+        for new_index in index + 1..=index + intersection {
+            count_winning_scratchcards(new_index, cards, counter)
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(cards: &Self::Input) -> Answer {
+        cards.iter().map(|card| card.points()).sum::<i32>().into()
+    }
+
+    fn part2(cards: &Self::Input) -> Answer {
+        let mut counter: HashMap<usize, usize> = HashMap::new();
+        for (index, _) in cards.iter().enumerate() {
+            count_winning_scratchcards(index, cards, &mut counter)
+        }
+
+        counter.values().sum::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 13, 30);
+    }
+}
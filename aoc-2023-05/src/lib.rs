@@ -0,0 +1,274 @@
+use aoc_common::{debug, parse_blocks, parse_field, Answer, ParseError, Solution};
+use aoc_interval::{IntervalSet, PiecewiseMap};
+use std::{ops::Range, str::FromStr};
+
+#[derive(Debug)]
+struct Map {
+    source_range: Range<usize>,
+    dest_start: usize,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let items: Vec<_> = s.split(' ').collect();
+        if items.len() != 3 {
+            return Err(ParseError::expected("destination, source and count", s));
+        }
+        let dest_start: usize = parse_field(items[0], "destination range start")?;
+        let src_start: usize = parse_field(items[1], "source range start")?;
+        let count: usize = parse_field(items[2], "range count")?;
+        let src_end = src_start
+            .checked_add(count)
+            .ok_or_else(|| ParseError::expected("range that fits in usize", items[2]))?;
+        Ok(Map {
+            source_range: src_start..src_end,
+            dest_start,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Maps {
+    name: String,
+    map: PiecewiseMap<usize>,
+}
+
+impl Maps {
+    fn map(&self, value: usize) -> usize {
+        self.map.map(value)
+    }
+
+    fn map_set(&self, set: &IntervalSet<usize>) -> IntervalSet<usize> {
+        self.map.map_set(set)
+    }
+}
+
+fn seed_to_location(seed: usize, maps: &[Maps]) -> usize {
+    let mut result = seed;
+    for m in maps {
+        result = m.map(result);
+    }
+    result
+}
+
+impl FromStr for Maps {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, maps) = s
+            .split_once(":\n")
+            .ok_or_else(|| ParseError::expected("map name followed by ranges", s))?;
+        let maps: Result<Vec<Map>, ParseError> = maps.lines().map(|line| line.parse()).collect();
+        let maps = maps?;
+        if maps.is_empty() {
+            return Err(ParseError::expected("at least one range", name));
+        }
+        let mut map = PiecewiseMap::new();
+        for m in maps {
+            map.insert(m.source_range, m.dest_start);
+        }
+
+        Ok(Maps {
+            name: name.to_string(),
+            map,
+        })
+    }
+}
+
+fn parse_seed_ranges(s: &str) -> Result<IntervalSet<usize>, ParseError> {
+    let mut result = IntervalSet::new();
+    let mut numbers = s.split(' ');
+    while let Some(start) = numbers.next() {
+        if let Some(count) = numbers.next() {
+            let start: usize = parse_field(start, "seed range start")?;
+            let end = start
+                .checked_add(parse_field(count, "seed range length")?)
+                .ok_or_else(|| ParseError::expected("range that fits in usize", count))?;
+            result.insert(start..end);
+        }
+    }
+    Ok(result)
+}
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    seed_ranges: IntervalSet<usize>,
+    maps: Vec<Maps>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (seeds_str, maps) = input.split_once("\n\n").unwrap_or((input, ""));
+        let seeds_str = seeds_str
+            .strip_prefix("seeds: ")
+            .ok_or_else(|| ParseError::expected("seeds: ...", seeds_str))?;
+        let seeds = seeds_str
+            .split(' ')
+            .map(|s| parse_field(s, "seed"))
+            .collect::<Result<Vec<usize>, _>>()?;
+
+        let maps: Vec<Maps> = parse_blocks(maps)?;
+
+        for m in maps.iter() {
+            debug!("{}", m.name);
+            for m in m.map.segments() {
+                debug!("  {:?}", m);
+            }
+        }
+
+        debug!("Seeds: {:?}", seeds);
+        let seed_ranges = parse_seed_ranges(seeds_str)?;
+        debug!("Seed ranges: {:?}", seed_ranges);
+
+        Ok(Almanac {
+            seeds,
+            seed_ranges,
+            maps,
+        })
+    }
+
+    fn part1(almanac: &Self::Input) -> Answer {
+        almanac
+            .seeds
+            .iter()
+            .map(|seed| seed_to_location(*seed, &almanac.maps))
+            .min()
+            .unwrap()
+            .into()
+    }
+
+    fn part2(almanac: &Self::Input) -> Answer {
+        almanac
+            .maps
+            .iter()
+            .fold(almanac.seed_ranges.clone(), |set, m| m.map_set(&set))
+            .min()
+            .unwrap()
+            .into()
+    }
+}
+
+/// Random valid inputs, for the differential tests and `aoc gen`
+#[cfg(any(test, feature = "generate"))]
+pub mod generate {
+    use proptest::prelude::*;
+
+    /// (destination start, source start, count) of every range of every map
+    pub type Ranges = Vec<Vec<(usize, usize, usize)>>;
+
+    /// Up to `size` seed ranges and maps, each map with up to `size`
+    /// non-overlapping ranges of at most `size` numbers.
+    pub fn seeds_and_maps(size: usize) -> impl Strategy<Value = (Vec<usize>, Ranges)> {
+        let seeds = prop::collection::vec((0..size * size, 1..=size), 1..=size)
+            .prop_map(|pairs| pairs.into_iter().flat_map(|(s, c)| [s, c]).collect());
+        let map = prop::collection::vec((0..size, 1..=size, 0..size * size), 1..=size).prop_map(
+            |ranges| {
+                let mut end = 0;
+                let mut map: Vec<_> = ranges
+                    .into_iter()
+                    .map(|(gap, count, dest)| {
+                        end += gap + count;
+                        (dest, end - count, count)
+                    })
+                    .collect();
+                map.reverse();
+                map
+            },
+        );
+        (seeds, prop::collection::vec(map, 1..=size))
+    }
+
+    pub fn almanac(seeds: &[usize], maps: &Ranges) -> String {
+        let seeds: Vec<_> = seeds.iter().map(|s| s.to_string()).collect();
+        let mut text = format!("seeds: {}\n", seeds.join(" "));
+        for (i, map) in maps.iter().enumerate() {
+            text.push_str(&format!("\nmap-{}-to-{} map:\n", i, i + 1));
+            for (dest, src, count) in map {
+                text.push_str(&format!("{} {} {}\n", dest, src, count));
+            }
+        }
+        text
+    }
+
+    /// The almanac of [`seeds_and_maps`].
+    pub fn input(size: usize) -> impl Strategy<Value = String> {
+        seeds_and_maps(size).prop_map(|(seeds, maps)| almanac(&seeds, &maps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::{almanac, seeds_and_maps, Ranges};
+    use crate::*;
+    use aoc_common::check_example;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_map_range() {
+        let mut map = PiecewiseMap::new();
+        map.insert(50..100, 1050);
+        map.insert(100..150, 100);
+        map.insert(150..200, 3050);
+        let maps = Maps {
+            name: String::from("input"),
+            map,
+        };
+        let map_range = |r: Range<usize>| maps.map_set(&r.into()).ranges().to_vec();
+
+        assert_eq!(map_range(10..20), vec![10..20]);
+        assert_eq!(map_range(10..50), vec![10..50]);
+        assert_eq!(map_range(10..60), [(10..50), (1050..1060)]);
+        assert_eq!(
+            map_range(10..160),
+            [(10..50), (100..150), (1050..1100), (3050..3060)]
+        );
+        assert_eq!(map_range(150..250), [(200..250), (3050..3100)]);
+    }
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 35, 46);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "seeds: 18446744073709551615 5";
+        let e = Day::parse(input).err().unwrap().locate(input);
+        assert_eq!((e.column, e.snippet.as_deref()), (Some(29), Some("5")));
+        let input = "seeds: 1 2\n\nseed-to-soil map:\n50 18446744073709551615 2\n";
+        aoc_common::set_strict(true);
+        let e = Day::parse(input).err().unwrap().locate(input);
+        aoc_common::set_strict(false);
+        assert_eq!((e.line, e.column), (Some(4), Some(25)));
+    }
+
+    fn brute_force_location(seed: usize, maps: &Ranges) -> usize {
+        maps.iter().fold(seed, |value, map| {
+            map.iter()
+                .find(|(_, src, count)| (*src..src + count).contains(&value))
+                .map_or(value, |(dest, src, _)| dest + (value - src))
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn prop_against_brute_force((seeds, maps) in seeds_and_maps(6)) {
+            let almanac = Day::parse(&almanac(&seeds, &maps)).unwrap();
+            let part1 = seeds.iter().map(|&s| brute_force_location(s, &maps)).min().unwrap();
+            let part2 = seeds
+                .chunks(2)
+                .flat_map(|pair| pair[0]..pair[0] + pair[1])
+                .map(|s| brute_force_location(s, &maps))
+                .min()
+                .unwrap();
+            prop_assert_eq!(Day::part1(&almanac), part1.into());
+            prop_assert_eq!(Day::part2(&almanac), part2.into());
+        }
+    }
+}
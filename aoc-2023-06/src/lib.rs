@@ -0,0 +1,105 @@
+use aoc_common::{parse_field, scan::Template, Answer, ParseError, Solution};
+
+const TIMES: Template = Template::new("Time:{}");
+const DISTANCES: Template = Template::new("Distance:{}");
+
+fn parse_numbers(s: &str) -> Result<Vec<usize>, ParseError> {
+    s.split_whitespace()
+        .map(|n| parse_field(n, "number"))
+        .collect()
+}
+
+/// All the numbers of the line read as a single one
+fn parse_number(s: &str) -> Result<usize, ParseError> {
+    s.replace(' ', "")
+        .parse()
+        .map_err(|_| ParseError::expected("number", s))
+}
+
+pub struct Races {
+    races: Vec<(usize, usize)>,
+    kerned_race: (usize, usize),
+}
+
+fn parse_input(s: &str) -> Result<Races, ParseError> {
+    let mut lines = s.split('\n').filter(|line| !line.is_empty());
+    let times = TIMES.scan(lines.next().unwrap_or_default())?.text();
+    let distances = DISTANCES.scan(lines.next().unwrap_or_default())?.text();
+
+    let (time_list, distance_list) = (parse_numbers(times)?, parse_numbers(distances)?);
+    if time_list.len() != distance_list.len() {
+        return Err(ParseError::expected("a distance for every time", distances));
+    }
+    let races = std::iter::zip(time_list, distance_list).collect();
+    let kerned_race = (parse_number(times)?, parse_number(distances)?);
+    Ok(Races { races, kerned_race })
+}
+
+fn simulate_race(race_time: usize, record_distance: usize) -> usize {
+    (1..race_time - 1)
+        .map(|button_time| {
+            let resulting_speed = button_time;
+            let remaining_time = race_time - button_time;
+            resulting_speed * remaining_time
+        })
+        .filter(|&distance| distance > record_distance)
+        .count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .races
+            .iter()
+            .map(|&(race_time, record_distance)| simulate_race(race_time, record_distance))
+            .product::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (race_time, record_distance) = input.kerned_race;
+        simulate_race(race_time, record_distance).into()
+    }
+}
+
+/// Random valid inputs for `aoc gen`
+#[cfg(feature = "generate")]
+pub mod generate {
+    use proptest::prelude::*;
+
+    /// Up to `size` races, but no more than four so that the kerned race stays short, each with a
+    /// record that can be beaten.
+    pub fn input(size: usize) -> impl Strategy<Value = String> {
+        let race = (3..=(10 * size).min(99)).prop_flat_map(|time| (Just(time), 0..time * time / 4));
+        prop::collection::vec(race, 1..=size.min(4)).prop_map(|races| {
+            let (times, distances): (Vec<_>, Vec<_>) = races
+                .iter()
+                .map(|(time, distance)| (format!("{:>5}", time), format!("{:>5}", distance)))
+                .unzip();
+            format!(
+                "Time:    {}\nDistance:{}\n",
+                times.concat(),
+                distances.concat()
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 288, 71503);
+    }
+}
@@ -1,6 +1,9 @@
+use aoc_common::{parse_field, parse_lines, Answer, ParseError, Solution};
 use core::cmp::Ordering;
 use counter::Counter;
+use std::str::FromStr;
 
+#[derive(Clone)]
 struct Hand {
     cards: Vec<u8>,
 }
@@ -15,19 +18,19 @@ impl Hand {
             .collect();
 
         if occurences == vec![5] {
-            return 6; // Five of a kind
+            6 // Five of a kind
         } else if occurences == vec![4, 1] {
-            return 5; // Four of a kind
+            5 // Four of a kind
         } else if occurences == vec![3, 2] {
-            return 4; // Full house
+            4 // Full house
         } else if occurences == vec![3, 1, 1] {
-            return 3; // Three of a kind
+            3 // Three of a kind
         } else if occurences == vec![2, 2, 1] {
-            return 2; // Two pair
+            2 // Two pair
         } else if counter.len() == 5 {
-            return 0; // High card
+            0 // High card
         } else {
-            return 1; // One pair
+            1 // One pair
         }
     }
 
@@ -35,7 +38,7 @@ impl Hand {
         // replace all jokers with card that has most occurences
         let other_cards: Counter<u8, usize> =
             Counter::from_iter(self.cards.iter().filter(|c| **c != 1).cloned());
-        if other_cards.len() == 0 {
+        if other_cards.is_empty() {
             return 6; // Five jokers of a kind
         }
 
@@ -47,7 +50,7 @@ impl Hand {
             .collect();
 
         let new_hand = Hand { cards: new_cards };
-        return new_hand.get_kind();
+        new_hand.get_kind()
     }
 
     fn compare_same_kind(&self, other: &Self) -> Ordering {
@@ -60,7 +63,8 @@ impl Hand {
     }
 }
 
-struct Row {
+#[derive(Clone)]
+pub struct Row {
     hand: Hand,
     bid: usize,
 }
@@ -90,37 +94,83 @@ impl PartialEq for Row {
 
 impl Eq for Row {}
 
-fn parse_hand(s: &str) -> Hand {
+fn parse_hand(s: &str) -> Result<Hand, ParseError> {
     let cards = s
-        .trim()
-        .chars()
-        .filter_map(|c| match c {
-            'T' => Some(10),
-            'J' => Some(1), // Joker
-            'Q' => Some(12),
-            'K' => Some(13),
-            'A' => Some(14),
-            _ => c.to_string().parse().ok(),
+        .char_indices()
+        .map(|(i, c)| match c {
+            'T' => Ok(10),
+            'J' => Ok(11),
+            'Q' => Ok(12),
+            'K' => Ok(13),
+            'A' => Ok(14),
+            '2'..='9' => Ok(c as u8 - b'0'),
+            _ => Err(ParseError::expected("card", &s[i..i + c.len_utf8()])),
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    Hand { cards }
+    Ok(Hand { cards })
 }
 
-fn parse_row(row: &str) -> Option<Row> {
-    let (hand, bid) = row.split_once(' ')?;
-    let hand = parse_hand(hand);
-    let bid = bid.parse::<usize>().ok()?;
-    Some(Row { hand, bid })
+impl Row {
+    /// J cards are jokers now, the weakest individual cards
+    fn with_jokers(&self) -> Row {
+        let cards = self
+            .hand
+            .cards
+            .iter()
+            .map(|&c| if c == 11 { 1 } else { c })
+            .collect();
+        Row {
+            hand: Hand { cards },
+            bid: self.bid,
+        }
+    }
 }
 
-fn main() {
-    let file_content = std::fs::read_to_string(std::env::args().nth(1).unwrap()).unwrap();
-    let mut rows: Vec<_> = file_content.split('\n').filter_map(parse_row).collect();
+fn total_winnings(mut rows: Vec<Row>) -> usize {
     rows.sort();
-
-    let result: usize = std::iter::zip(1.., rows)
+    std::iter::zip(1.., rows)
         .map(|(rank, row)| rank * row.bid)
-        .sum();
-    println!("{}", result);
+        .sum()
+}
+
+impl FromStr for Row {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hand, bid) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::expected("hand and bid", s))?;
+        let hand = parse_hand(hand)?;
+        let bid = parse_field(bid, "bid")?;
+        Ok(Row { hand, bid })
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Row>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(rows: &Self::Input) -> Answer {
+        total_winnings(rows.clone()).into()
+    }
+
+    fn part2(rows: &Self::Input) -> Answer {
+        total_winnings(rows.iter().map(|row| row.with_jokers()).collect()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 6440, 5905);
+    }
 }
@@ -0,0 +1,201 @@
+use aoc_common::{assume::Assumption, parse_lines, Answer, ParseError, Solution};
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, PartialEq)]
+enum Instruction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    left: String,
+    right: String,
+}
+
+pub struct World {
+    instructions: Vec<Instruction>,
+    nodes: HashMap<String, Node>,
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // AAA = (BBB, CCC)
+        let (name, targets) = s
+            .split_once(" = ")
+            .ok_or_else(|| ParseError::expected("NAME = (LEFT, RIGHT)", s))?;
+        let (left, right) = targets
+            .strip_prefix('(')
+            .and_then(|t| t.strip_suffix(')'))
+            .and_then(|t| t.split_once(", "))
+            .ok_or_else(|| ParseError::expected("(LEFT, RIGHT)", targets))?;
+        Ok(Node {
+            name: name.into(),
+            left: left.into(),
+            right: right.into(),
+        })
+    }
+}
+
+impl FromStr for World {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (instructions, nodes) = s
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new("missing blank line after instructions"))?;
+
+        let instructions = instructions
+            .trim()
+            .char_indices()
+            .map(|(i, c)| match c {
+                'L' => Ok(Instruction::Left),
+                'R' => Ok(Instruction::Right),
+                _ => Err(ParseError::expected(
+                    "L or R",
+                    &instructions.trim()[i..i + c.len_utf8()],
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+
+        let nodes = parse_lines::<Node>(nodes)?
+            .into_iter()
+            .map(|node| (node.name.clone(), node))
+            .collect();
+
+        Ok(World {
+            instructions,
+            nodes,
+        })
+    }
+}
+
+impl World {
+    fn step(&self, current_node: &str, instruction: &Instruction) -> String {
+        if instruction == &Instruction::Left {
+            self.nodes[current_node].left.clone()
+        } else {
+            self.nodes[current_node].right.clone()
+        }
+    }
+
+    fn path_len(&self, start: &str, is_end: fn(&str) -> bool) -> usize {
+        let mut current_node = start.to_string();
+        let instructions = std::iter::zip(1usize.., self.instructions.iter().cycle());
+        for (counter, instruction) in instructions {
+            current_node = self.step(&current_node, instruction);
+            if is_end(&current_node) {
+                return counter;
+            }
+        }
+        0
+    }
+
+    fn start_nodes(&self) -> Vec<String> {
+        self.nodes
+            .keys()
+            .filter(|n| n.ends_with('A'))
+            .cloned()
+            .collect()
+    }
+
+    /// Steps at which the path from `start` reaches the first two end nodes, None if it
+    /// loops without them or leaves the map
+    fn end_steps(&self, start: &str) -> Option<(usize, usize)> {
+        // past this many steps the path only repeats itself
+        let limit = 2 * self.nodes.len() * self.instructions.len();
+        let mut current_node = start;
+        let mut ends = vec![];
+        for (step, instruction) in (1..=limit).zip(self.instructions.iter().cycle()) {
+            let node = self.nodes.get(current_node)?;
+            current_node = match instruction {
+                Instruction::Left => &node.left,
+                Instruction::Right => &node.right,
+            };
+            if current_node.ends_with('Z') {
+                ends.push(step);
+                if let [first, second] = ends[..] {
+                    return Some((first, second));
+                }
+            }
+        }
+        None
+    }
+
+    /// The LCM of the first ends is only right when every path keeps coming back to its end
+    /// node with the period of reaching it from the start
+    fn check_cycles(&self) -> Result<(), String> {
+        for start in self.start_nodes() {
+            match self.end_steps(&start) {
+                Some((first, second)) if second == 2 * first => {}
+                Some((first, second)) => {
+                    return Err(format!(
+                        "the path from {} ends after {} and {} steps",
+                        start, first, second
+                    ))
+                }
+                None => return Err(format!("the path from {} does not end twice", start)),
+            }
+        }
+        Ok(())
+    }
+
+    fn path_parallel(&self) -> usize {
+        let start_nodes = self.start_nodes();
+
+        let counters: Vec<_> = start_nodes
+            .iter()
+            .map(|start_node| self.path_len(start_node, |node| node.ends_with('Z')))
+            .collect();
+
+        counters.iter().fold(1, |acc, v| num::integer::lcm(acc, *v))
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = World;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(world: &Self::Input) -> Answer {
+        world.path_len("AAA", |node| node == "ZZZ").into()
+    }
+
+    fn part2(world: &Self::Input) -> Answer {
+        world.path_parallel().into()
+    }
+
+    fn assumptions(world: &Self::Input) -> Vec<Assumption> {
+        vec![Assumption::part2(
+            "every ghost reaches its end node again and again with the same period",
+            world.check_cycles(),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{check_part1, check_part2, parse_example};
+
+    #[test]
+    fn test_example() {
+        check_part1::<Day>(include_str!("../example.txt"), 2);
+        check_part2::<Day>(include_str!("../example2.txt"), 6);
+    }
+
+    #[test]
+    fn test_assumptions() {
+        let world = parse_example::<Day>(include_str!("../example2.txt"));
+        assert_eq!(world.check_cycles(), Ok(()));
+        // AAA reaches ZZZ after 1 and 3 steps
+        let world =
+            parse_example::<Day>("LR\n\nAAA = (ZZZ, BBB)\nBBB = (ZZZ, ZZZ)\nZZZ = (BBB, BBB)\n");
+        assert!(world.check_cycles().is_err());
+    }
+}
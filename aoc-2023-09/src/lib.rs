@@ -0,0 +1,102 @@
+use aoc_common::{parse_field, Answer, ParseError, Solution};
+
+fn parse_line(s: &str) -> Result<Vec<i32>, ParseError> {
+    s.split_whitespace()
+        .map(|v| parse_field(v, "number"))
+        .collect()
+}
+
+fn calc_diff(values: &[i32]) -> Vec<i32> {
+    values.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+fn predict_next(values: &[i32]) -> i32 {
+    let diff = calc_diff(values);
+    if values.iter().all(|&v| v == 0) {
+        return 0;
+    }
+
+    predict_next(&diff) + values.last().unwrap()
+}
+
+fn predict_prev(values: &[i32]) -> i32 {
+    let diff = calc_diff(values);
+    if values.iter().all(|&v| v == 0) {
+        return 0;
+    }
+
+    values.first().unwrap() - predict_prev(&diff)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split('\n')
+            .filter(|line| !line.is_empty())
+            .map(parse_line)
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .map(|line| predict_next(line))
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .map(|line| predict_prev(line))
+            .sum::<i32>()
+            .into()
+    }
+}
+
+/// Random valid inputs for `aoc gen`
+#[cfg(feature = "generate")]
+pub mod generate {
+    use proptest::prelude::*;
+
+    /// Up to `size` histories of 21 values of a polynomial of at most the fourth degree, as in the
+    /// puzzle.
+    pub fn input(size: usize) -> impl Strategy<Value = String> {
+        let polynomial = prop::collection::vec(-9..=9i32, 1..=5);
+        prop::collection::vec(polynomial, 1..=size).prop_map(|polynomials| {
+            polynomials
+                .iter()
+                .map(|coefficients| {
+                    let values: Vec<_> = (0..21)
+                        .map(|x| coefficients.iter().rev().fold(0, |value, c| value * x + c))
+                        .map(|value| value.to_string())
+                        .collect();
+                    values.join(" ") + "\n"
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 114, 2);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "0 3 6\n1 x 3\n";
+        let e = Day::parse(input).unwrap_err().locate(input);
+        assert_eq!((e.line, e.column), (Some(2), Some(3)));
+        assert_eq!(e.snippet.as_deref(), Some("x"));
+    }
+}
@@ -0,0 +1,248 @@
+use aoc_common::{debug, Answer, ParseError, Solution};
+use aoc_grid::{Dir4, Grid, Pos};
+use aoc_image::{Image, BLACK, BLUE, RED, YELLOW};
+use std::{collections::HashSet, str::FromStr};
+
+#[derive(Debug)]
+pub struct World {
+    pipes: Grid<HashSet<Dir4>>,
+    start: Pos,
+}
+
+/// The directions a tile connects to; the start and the ground connect to none
+fn pipe(c: char) -> Option<HashSet<Dir4>> {
+    match c {
+        '|' => Some(HashSet::from([Dir4::Up, Dir4::Down])),
+        '-' => Some(HashSet::from([Dir4::Right, Dir4::Left])),
+        'L' => Some(HashSet::from([Dir4::Up, Dir4::Right])),
+        'J' => Some(HashSet::from([Dir4::Up, Dir4::Left])),
+        '7' => Some(HashSet::from([Dir4::Down, Dir4::Left])),
+        'F' => Some(HashSet::from([Dir4::Down, Dir4::Right])),
+        'S' | '.' => Some(HashSet::new()),
+        _ => None,
+    }
+}
+
+impl FromStr for World {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pipes = Grid::parse_with(s, pipe)?;
+        let start = s
+            .lines()
+            .filter(|line| !line.is_empty())
+            .enumerate()
+            .find_map(|(r, line)| Some((r, line.chars().position(|c| c == 'S')?)))
+            .ok_or_else(|| ParseError::new("no start"))?;
+        Ok(World { pipes, start })
+    }
+}
+
+impl World {
+    fn starting_directions(&self) -> Vec<Dir4> {
+        Dir4::ALL
+            .into_iter()
+            .filter(|&d| {
+                self.pipes
+                    .get_offset(self.start, d.offset())
+                    .is_some_and(|pipe| pipe.contains(&d.opposite()))
+            })
+            .collect()
+    }
+
+    /// The directions the loop leaves the tile at `pos` in
+    fn connections(&self, pos: Pos) -> HashSet<Dir4> {
+        if pos == self.start {
+            self.starting_directions().into_iter().collect()
+        } else {
+            self.pipes[pos].clone()
+        }
+    }
+
+    fn step(&self, from: Pos, in_direction: Dir4) -> (Pos, Dir4) {
+        let pos = self.pipes.offset(from, in_direction.offset()).unwrap();
+        let out_direction = self.pipes[pos]
+            .iter()
+            .find(|&&d| d != in_direction.opposite())
+            .unwrap();
+
+        (pos, *out_direction)
+    }
+
+    /// Returns the tiles of the loop and the number of steps to its farthest point
+    fn compute_loop(&self) -> (HashSet<Pos>, usize) {
+        let start_directions = self.starting_directions();
+        debug!("starting directions: {:?}", start_directions);
+        let mut loop_tiles = HashSet::from([self.start]);
+
+        let mut first = (self.start, start_directions[0]);
+        let mut second = (self.start, start_directions[1]);
+
+        let mut steps = 0;
+        for step in 1.. {
+            steps = step;
+            first = self.step(first.0, first.1);
+            second = self.step(second.0, second.1);
+            loop_tiles.extend([first.0, second.0]);
+
+            debug!("step {}: {:?} {:?}", step, first.0, second.0);
+
+            if first.0 == second.0 {
+                break;
+            }
+        }
+
+        (loop_tiles, steps)
+    }
+}
+
+/// The cells of the tile at `pos` on the tripled map: its center and the ones towards its
+/// connections
+fn tripled(areas: &Grid<Area>, pos: Pos, connections: &HashSet<Dir4>) -> Vec<Pos> {
+    let center = (pos.0 * 3 + 1, pos.1 * 3 + 1);
+    std::iter::once(center)
+        .chain(
+            connections
+                .iter()
+                .filter_map(|d| areas.offset(center, d.offset())),
+        )
+        .collect()
+}
+
+/// Marks the gaps reached from `from` as outside
+fn flood_fill(areas: &mut Grid<Area>, from: Pos) {
+    let mut stack = vec![from];
+    while let Some(pos) = stack.pop() {
+        if areas[pos] != Area::Gap {
+            continue;
+        }
+        areas[pos] = Area::Outside;
+        stack.extend(areas.neighbours4(pos));
+    }
+}
+
+fn is_original_coord((r, c): Pos) -> bool {
+    r % 3 == 1 && c % 3 == 1
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Area {
+    Loop,
+    /// Reached from the border without crossing the loop
+    Outside,
+    /// An original tile enclosed by the loop
+    Inside,
+    /// Enclosed, but only the space between two original tiles
+    Gap,
+}
+
+/// Classifies every cell of the tripled map
+fn classify(world: &World) -> Grid<Area> {
+    let (loop_tiles, _) = world.compute_loop();
+    let mut areas = Grid::new(world.pipes.width() * 3, world.pipes.height() * 3, Area::Gap);
+    for &pos in &loop_tiles {
+        for cell in tripled(&areas, pos, &world.connections(pos)) {
+            areas[cell] = Area::Loop;
+        }
+    }
+    // the border of the tripled map is never on the loop
+    flood_fill(&mut areas, (0, 0));
+    for pos in areas.positions().collect::<Vec<_>>() {
+        if areas[pos] == Area::Gap && is_original_coord(pos) {
+            areas[pos] = Area::Inside;
+        }
+    }
+    areas
+}
+
+fn picture(areas: &Grid<Area>) -> Image {
+    Image::from_grid(areas, |area| match area {
+        Area::Loop => RED,
+        Area::Outside => BLUE,
+        Area::Inside => YELLOW,
+        Area::Gap => BLACK,
+    })
+}
+
+/// The tripled map with the loop as X and the original tiles outside and inside as O and I
+fn draw(areas: &Grid<Area>) -> String {
+    let rows: Vec<String> = areas
+        .rows()
+        .enumerate()
+        .map(|(r, row)| {
+            let row: String = row
+                .iter()
+                .enumerate()
+                .map(|(c, area)| match area {
+                    Area::Loop => 'X',
+                    Area::Outside if is_original_coord((r, c)) => 'O',
+                    Area::Inside => 'I',
+                    _ => ' ',
+                })
+                .collect();
+            row.trim_end().to_string()
+        })
+        .collect();
+    rows.join("\n")
+}
+
+fn count_inner(world: &World) -> usize {
+    let areas = classify(world);
+    debug!("{}", draw(&areas));
+    aoc_image::capture(|| picture(&areas));
+    areas
+        .iter()
+        .filter(|(_, area)| **area == Area::Inside)
+        .count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = World;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(world: &Self::Input) -> Answer {
+        world.compute_loop().1.into()
+    }
+
+    fn part2(world: &Self::Input) -> Answer {
+        count_inner(world).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{assert_snapshot, check_part1, check_part2, parse_example};
+
+    #[test]
+    fn test_example() {
+        check_part1::<Day>(include_str!("../example.txt"), 4);
+        check_part2::<Day>(include_str!("../example2.txt"), 10);
+    }
+
+    #[test]
+    fn test_picture() {
+        let world = parse_example::<Day>(include_str!("../example2.txt"));
+        let path = std::env::temp_dir().join(format!("aoc-2023-10-{}.png", std::process::id()));
+        aoc_image::write_png(&path, &picture(&classify(&world)), 2).unwrap();
+        let image = aoc_image::read_png(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!((image.width(), image.height()), (120, 60));
+        let pixel = |(r, c): (usize, usize)| image.get((2 * r, 2 * c));
+        assert_eq!(pixel((0, 0)), BLUE);
+        // the start, and a tile enclosed by the loop
+        assert_eq!(pixel((1, 13)), RED);
+        assert_eq!(pixel((13, 31)), YELLOW);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let world = parse_example::<Day>(include_str!("../example2.txt"));
+        assert_snapshot!("areas", draw(&classify(&world)));
+    }
+}
@@ -1,29 +1,29 @@
+use aoc_common::{debug, Answer, ParseError, Solution};
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 struct Coord {
     x: usize,
     y: usize,
 }
 
-#[derive(Debug)]
-struct Universe {
+#[derive(Debug, Clone)]
+pub struct Universe {
     galaxies: Vec<Coord>,
 }
 
-#[derive(Debug)]
-struct UniverseParseError {}
-
 impl FromStr for Universe {
-    type Err = UniverseParseError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let lines = s.split('\n').filter(|line| !line.is_empty());
         let mut galaxies = Vec::new();
 
         for (y, line) in lines.enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                if c == '#' {
-                    galaxies.push(Coord { x, y });
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                match c {
+                    '#' => galaxies.push(Coord { x, y }),
+                    '.' => {}
+                    _ => return Err(ParseError::expected("# or .", &line[i..i + c.len_utf8()])),
                 }
             }
         }
@@ -42,15 +42,13 @@ impl Universe {
     fn print(&self) {
         let boundary = self.boundary();
         for y in 0..=boundary.y {
-            for x in 0..=boundary.x {
-                let coord = Coord { x, y };
-                if self.galaxies.contains(&coord) {
-                    print!("#");
-                } else {
-                    print!(".");
-                }
-            }
-            println!();
+            let row: String = (0..=boundary.x)
+                .map(|x| match self.galaxies.contains(&Coord { x, y }) {
+                    true => '#',
+                    false => '.',
+                })
+                .collect();
+            debug!("{}", row);
         }
     }
 
@@ -109,11 +107,41 @@ impl Universe {
     }
 }
 
-fn main() {
-    let file_content = std::fs::read_to_string(std::env::args().nth(1).unwrap()).unwrap();
-    let mut universe = file_content.parse::<Universe>().unwrap();
-    universe.expand(1_000_000);
+fn sum_of_distances(universe: &Universe, grow_factor: usize) -> usize {
+    let mut universe = universe.clone();
+    universe.expand(grow_factor);
     let pairs = universe.galaxy_pairs();
-    let result: usize = pairs.iter().map(|pair| universe.distance(pair)).sum();
-    println!("{}", result);
+    pairs.iter().map(|pair| universe.distance(pair)).sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Universe;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(universe: &Self::Input) -> Answer {
+        sum_of_distances(universe, 2).into()
+    }
+
+    fn part2(universe: &Self::Input) -> Answer {
+        sum_of_distances(universe, 1_000_000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::parse_example;
+
+    #[test]
+    fn test_example() {
+        let universe = parse_example::<Day>(include_str!("../example.txt"));
+        assert_eq!(sum_of_distances(&universe, 2), 374);
+        assert_eq!(sum_of_distances(&universe, 10), 1030);
+        assert_eq!(sum_of_distances(&universe, 100), 8410);
+    }
 }
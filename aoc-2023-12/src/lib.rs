@@ -0,0 +1,251 @@
+use aoc_common::{parallel, parse_field, parse_lines, Answer, ParseError, Solution};
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+#[derive(Debug, PartialEq, Clone)]
+enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(Debug)]
+pub struct Row {
+    springs: Vec<Spring>,
+    checksum: Vec<usize>,
+}
+
+impl FromStr for Row {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (springs_str, checksum) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::expected("springs and checksum", s))?;
+        let springs: Vec<Spring> = springs_str
+            .char_indices()
+            .map(|(i, c)| match c {
+                '.' => Ok(Spring::Operational),
+                '#' => Ok(Spring::Damaged),
+                '?' => Ok(Spring::Unknown),
+                _ => Err(ParseError::expected(
+                    "one of . # ?",
+                    &springs_str[i..i + c.len_utf8()],
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        let checksum: Vec<usize> = checksum
+            .split(',')
+            .map(|s| parse_field(s, "group size"))
+            .collect::<Result<_, _>>()?;
+        Ok(Row { springs, checksum })
+    }
+}
+
+fn springs_to_string(springs: &[Spring]) -> String {
+    springs
+        .iter()
+        .map(|v| match v {
+            Spring::Operational => '.',
+            Spring::Damaged => '#',
+            Spring::Unknown => '?',
+        })
+        .collect()
+}
+
+impl Display for Row {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {:?}",
+            springs_to_string(&self.springs),
+            self.checksum
+        )
+    }
+}
+
+impl Row {
+    fn count_arrangements(&self) -> usize {
+        let mut cache: HashMap<(usize, usize, usize), usize> = HashMap::new();
+        self.count_arrangements_recursive(&mut cache, 0, 0, 0)
+    }
+
+    fn count_arrangements_recursive(
+        &self,
+        cache: &mut HashMap<(usize, usize, usize), usize>,
+        pos: usize,
+        block_index: usize,
+        block_pos: usize,
+    ) -> usize {
+        let key = (pos, block_index, block_pos);
+        if let Some(cached) = cache.get(&key) {
+            return *cached;
+        }
+        if pos == self.springs.len() {
+            let valid_over_last_block = block_index == self.checksum.len() && block_pos == 0;
+            let valid =
+                block_index == self.checksum.len() - 1 && self.checksum[block_index] == block_pos;
+
+            return if valid || valid_over_last_block { 1 } else { 0 };
+        }
+
+        let mut result = 0;
+        for substitute in [Spring::Operational, Spring::Damaged] {
+            if self.springs[pos] == substitute || self.springs[pos] == Spring::Unknown {
+                if substitute == Spring::Operational && block_pos == 0 {
+                    // just move to the next spring
+                    result += self.count_arrangements_recursive(cache, pos + 1, block_index, 0);
+                } else if substitute == Spring::Operational
+                    && block_pos > 0
+                    && block_index < self.checksum.len()
+                    && self.checksum[block_index] == block_pos
+                {
+                    // block of damaged springs ended -> increment block_index
+                    result += self.count_arrangements_recursive(cache, pos + 1, block_index + 1, 0);
+                } else if substitute == Spring::Damaged {
+                    // next damaged spring in a sequence of damaged springs -> increment block_pos
+                    result += self.count_arrangements_recursive(
+                        cache,
+                        pos + 1,
+                        block_index,
+                        block_pos + 1,
+                    );
+                }
+            }
+        }
+        cache.insert(key, result);
+        result
+    }
+
+    fn extend(&self) -> Self {
+        let mut springs = self.springs.clone();
+        for _ in 1..5 {
+            springs.push(Spring::Unknown);
+            springs.extend(self.springs.iter().cloned());
+        }
+
+        let mut checksum = vec![];
+        for _ in 0..5 {
+            checksum.extend(self.checksum.iter().cloned());
+        }
+
+        Row { springs, checksum }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Row>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(rows: &Self::Input) -> Answer {
+        parallel::map(rows, Row::count_arrangements)
+            .into_iter()
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(rows: &Self::Input) -> Answer {
+        parallel::map(rows, |row| row.extend().count_arrangements())
+            .into_iter()
+            .sum::<usize>()
+            .into()
+    }
+}
+
+/// Random valid inputs, for the differential tests and `aoc gen`
+#[cfg(any(test, feature = "generate"))]
+pub mod generate {
+    use proptest::prelude::*;
+
+    /// A row of up to `size` springs with a checksum that fits at least one
+    /// arrangement: a random arrangement with some of the springs hidden.
+    pub fn row(size: usize) -> impl Strategy<Value = String> {
+        prop::collection::vec((any::<bool>(), prop::bool::weighted(0.4)), 1..=size)
+            .prop_filter("no damaged spring", |springs| {
+                springs.iter().any(|(damaged, _)| *damaged)
+            })
+            .prop_map(|springs| {
+                let arrangement: String = springs
+                    .iter()
+                    .map(|(damaged, _)| if *damaged { '#' } else { '.' })
+                    .collect();
+                let hidden: String = springs
+                    .iter()
+                    .map(|(damaged, unknown)| match (damaged, unknown) {
+                        (_, true) => '?',
+                        (true, false) => '#',
+                        (false, false) => '.',
+                    })
+                    .collect();
+                format!("{} {}", hidden, groups(&arrangement).join(","))
+            })
+    }
+
+    pub fn groups(arrangement: &str) -> Vec<String> {
+        arrangement
+            .split('.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect()
+    }
+
+    /// Up to `size` rows of up to `size` springs.
+    pub fn input(size: usize) -> impl Strategy<Value = String> {
+        prop::collection::vec(row(size), 1..=size).prop_map(|rows| rows.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::{groups, row};
+    use crate::*;
+    use aoc_common::check_example;
+    use proptest::prelude::*;
+
+    /// Tries every way of filling in the unknown springs.
+    fn brute_force(row: &Row) -> usize {
+        let springs = springs_to_string(&row.springs);
+        let unknown: Vec<usize> = springs.match_indices('?').map(|(i, _)| i).collect();
+        let checksum: Vec<String> = row.checksum.iter().map(|n| n.to_string()).collect();
+        (0..1usize << unknown.len())
+            .filter(|mask| {
+                let mut arrangement: Vec<u8> = springs.clone().into_bytes();
+                for (bit, &i) in unknown.iter().enumerate() {
+                    arrangement[i] = if mask & (1 << bit) != 0 { b'#' } else { b'.' };
+                }
+                groups(std::str::from_utf8(&arrangement).unwrap()) == checksum
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 21, 525152);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn prop_count_arrangements(line in row(12)) {
+            let row: Row = line.parse().unwrap();
+            let count = row.count_arrangements();
+            prop_assert!(count >= 1);
+            prop_assert_eq!(count, brute_force(&row));
+        }
+    }
+
+    proptest! {
+        // the unfolded rows are long enough to make the brute force slow
+        #![proptest_config(ProptestConfig::with_cases(200))]
+
+        #[test]
+        fn prop_count_extended(line in row(2)) {
+            let row = line.parse::<Row>().unwrap().extend();
+            prop_assert_eq!(row.count_arrangements(), brute_force(&row));
+        }
+    }
+}
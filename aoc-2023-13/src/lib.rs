@@ -0,0 +1,157 @@
+use aoc_common::{debug, Answer, ParseError, Solution};
+use aoc_grid::Grid;
+
+type Pattern = Grid<char>;
+
+#[derive(Debug)]
+struct Symmetry {
+    vertical: Option<usize>,
+    horizontal: Option<usize>,
+}
+
+fn check_vertical_symmetry(p: &Pattern, row: usize) -> bool {
+    for shift in 0.. {
+        let upper_row_index: i32 = row as i32 - shift as i32;
+        let lower_row_index = row + shift + 1;
+
+        if upper_row_index < 0 || lower_row_index >= p.height() {
+            break;
+        }
+
+        if p.row(upper_row_index as usize) != p.row(lower_row_index) {
+            return false;
+        }
+    }
+    true
+}
+
+fn vertical_symmetry(p: &Pattern) -> Vec<usize> {
+    let mut result = vec![];
+    for row in 0..p.height() - 1 {
+        if check_vertical_symmetry(p, row) {
+            result.push(row + 1);
+        }
+    }
+    result
+}
+
+fn check_horizontal_symmetry(p: &Pattern, col: usize) -> bool {
+    for shift in 0.. {
+        let left_col_index: i32 = col as i32 - shift as i32;
+        let right_col_index = col + shift + 1;
+
+        if left_col_index < 0 || right_col_index >= p.width() {
+            break;
+        }
+
+        if !p
+            .column(left_col_index as usize)
+            .eq(p.column(right_col_index))
+        {
+            return false;
+        }
+    }
+    true
+}
+
+fn horizontal_symmetry(p: &Pattern) -> Vec<usize> {
+    let mut result = vec![];
+    for col in 0..p.width() - 1 {
+        if check_horizontal_symmetry(p, col) {
+            result.push(col + 1);
+        }
+    }
+    result
+}
+
+fn get_original_symmetry(p: &Pattern) -> Symmetry {
+    Symmetry {
+        horizontal: horizontal_symmetry(p).first().cloned(),
+        vertical: vertical_symmetry(p).first().cloned(),
+    }
+}
+
+/// Symmetry after fixing the smudge
+fn get_symmetry(p: &Pattern) -> Symmetry {
+    let old_h = horizontal_symmetry(p);
+    let old_v = vertical_symmetry(p);
+    for pos in p.positions() {
+        let mut candidate = p.clone();
+
+        let original = p[pos];
+        if original == '.' {
+            candidate[pos] = '#';
+        } else if original == '#' {
+            candidate[pos] = '.';
+        } else {
+            panic!()
+        }
+
+        let new_h = horizontal_symmetry(&candidate);
+        let new_v = vertical_symmetry(&candidate);
+
+        for h in new_h {
+            if !old_h.contains(&h) {
+                return Symmetry {
+                    horizontal: Some(h),
+                    vertical: None,
+                };
+            }
+        }
+        for v in new_v {
+            if !old_v.contains(&v) {
+                return Symmetry {
+                    horizontal: None,
+                    vertical: Some(v),
+                };
+            }
+        }
+    }
+    panic!("No symmetry found!");
+}
+
+fn summarize(patterns: &[Pattern], get_symmetry: fn(&Pattern) -> Symmetry) -> usize {
+    let mut result = 0;
+    for p in patterns.iter() {
+        debug!("{}", p);
+        let symmetry = get_symmetry(p);
+        debug!("{:?}", symmetry);
+
+        if let Some(vertical) = symmetry.vertical {
+            result += 100 * vertical;
+        }
+        if let Some(horizontal) = symmetry.horizontal {
+            result += horizontal;
+        }
+    }
+    result
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.split("\n\n").map(|p| p.parse()).collect()
+    }
+
+    fn part1(patterns: &Self::Input) -> Answer {
+        summarize(patterns, get_original_symmetry).into()
+    }
+
+    fn part2(patterns: &Self::Input) -> Answer {
+        summarize(patterns, get_symmetry).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 405, 400);
+    }
+}
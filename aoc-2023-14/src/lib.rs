@@ -1,9 +1,11 @@
-use std::{collections::hash_map::DefaultHasher, collections::HashMap, hash::Hasher};
+use aoc_common::anim::{self, Color, Frame};
+use aoc_common::{cycle::skip_cycles, debug, Answer, ParseError, Solution};
+use aoc_grid::{Grid, Tile};
 
-type World = Vec<Vec<Rock>>;
+type World = Grid<Rock>;
 
-#[derive(Clone, PartialEq)]
-enum Rock {
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum Rock {
     Rounded,
     CubeShaped,
     Empty,
@@ -15,36 +17,22 @@ struct Hole {
     round_count: usize,
 }
 
-fn parse_world(s: &str) -> World {
-    let mut result = vec![];
-
-    for line in s.lines() {
-        let row = line
-            .chars()
-            .filter_map(|c| match c {
-                'O' => Some(Rock::Rounded),
-                '#' => Some(Rock::CubeShaped),
-                '.' => Some(Rock::Empty),
-                _ => None,
-            })
-            .collect();
-        result.push(row);
+impl Tile for Rock {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'O' => Some(Rock::Rounded),
+            '#' => Some(Rock::CubeShaped),
+            '.' => Some(Rock::Empty),
+            _ => None,
+        }
     }
 
-    result
-}
-
-fn print_world(w: &World) {
-    for row in w {
-        let row = row
-            .iter()
-            .map(|r| match r {
-                Rock::Rounded => 'O',
-                Rock::CubeShaped => '#',
-                Rock::Empty => '.',
-            })
-            .collect::<String>();
-        println!("{}", row);
+    fn to_char(&self) -> char {
+        match self {
+            Rock::Rounded => 'O',
+            Rock::CubeShaped => '#',
+            Rock::Empty => '.',
+        }
     }
 }
 
@@ -54,8 +42,7 @@ fn get_vertical_holes(w: &World, col: usize) -> Vec<Hole> {
     let mut in_hole = false;
     let mut start = 0;
     let mut round_count = 0;
-    for r in 0..w.len() {
-        let rock = &w[r][col];
+    for (r, rock) in w.column(col).enumerate() {
         if rock == &Rock::CubeShaped {
             if in_hole {
                 holes.push(Hole {
@@ -79,7 +66,7 @@ fn get_vertical_holes(w: &World, col: usize) -> Vec<Hole> {
     if in_hole {
         holes.push(Hole {
             start,
-            len: w.len() - start,
+            len: w.height() - start,
             round_count,
         });
     }
@@ -93,8 +80,7 @@ fn get_horizontal_holes(w: &World, row: usize) -> Vec<Hole> {
     let mut in_hole = false;
     let mut start = 0;
     let mut round_count = 0;
-    for c in 0..w[0].len() {
-        let rock = &w[row][c];
+    for (c, rock) in w.row(row).iter().enumerate() {
         if rock == &Rock::CubeShaped {
             if in_hole {
                 holes.push(Hole {
@@ -118,7 +104,7 @@ fn get_horizontal_holes(w: &World, row: usize) -> Vec<Hole> {
     if in_hole {
         holes.push(Hole {
             start,
-            len: w[0].len() - start,
+            len: w.width() - start,
             round_count,
         });
     }
@@ -127,7 +113,7 @@ fn get_horizontal_holes(w: &World, row: usize) -> Vec<Hole> {
 }
 
 fn tilt_north(w: &mut World) {
-    for c in 0..w[0].len() {
+    for c in 0..w.width() {
         let holes = get_vertical_holes(w, c);
         for hole in holes {
             for r in 0..hole.len {
@@ -136,15 +122,16 @@ fn tilt_north(w: &mut World) {
                 } else {
                     Rock::Empty
                 };
-                w[hole.start + r][c] = rock;
+                w[(hole.start + r, c)] = rock;
             }
         }
     }
 }
 
 fn tilt_east(w: &mut World) {
-    for r in 0..w.len() {
+    for r in 0..w.height() {
         let holes = get_horizontal_holes(w, r);
+        let row = w.row_mut(r);
         for hole in holes {
             for (i, c) in (0..hole.len).rev().enumerate() {
                 let rock = if i < hole.round_count {
@@ -152,14 +139,14 @@ fn tilt_east(w: &mut World) {
                 } else {
                     Rock::Empty
                 };
-                w[r][hole.start + c] = rock;
+                row[hole.start + c] = rock;
             }
         }
     }
 }
 
 fn tilt_south(w: &mut World) {
-    for c in 0..w[0].len() {
+    for c in 0..w.width() {
         let holes = get_vertical_holes(w, c);
         for hole in holes {
             for (i, r) in (0..hole.len).rev().enumerate() {
@@ -168,15 +155,16 @@ fn tilt_south(w: &mut World) {
                 } else {
                     Rock::Empty
                 };
-                w[hole.start + r][c] = rock;
+                w[(hole.start + r, c)] = rock;
             }
         }
     }
 }
 
 fn tilt_west(w: &mut World) {
-    for r in 0..w.len() {
+    for r in 0..w.height() {
         let holes = get_horizontal_holes(w, r);
+        let row = w.row_mut(r);
         for hole in holes {
             for c in 0..hole.len {
                 let rock = if c < hole.round_count {
@@ -184,7 +172,7 @@ fn tilt_west(w: &mut World) {
                 } else {
                     Rock::Empty
                 };
-                w[r][hole.start + c] = rock;
+                row[hole.start + c] = rock;
             }
         }
     }
@@ -192,65 +180,86 @@ fn tilt_west(w: &mut World) {
 
 fn compute_load(w: &World) -> usize {
     let mut load = 0;
-    for (i, row) in w.iter().enumerate() {
+    for (i, row) in w.rows().enumerate() {
         let round_count = row.iter().filter(|r| r == &&Rock::Rounded).count();
-        load += (w.len() - i) * round_count;
+        load += (w.height() - i) * round_count;
     }
     load
 }
 
-fn hash(w: &World) -> u64 {
-    let mut hasher = DefaultHasher::new();
-    for r in 0..w.len() {
-        for c in 0..w[0].len() {
-            if w[r][c] == Rock::Rounded {
-                hasher.write_usize(r);
-                hasher.write_usize(c);
-            }
-        }
-    }
-    hasher.finish()
+fn frame(w: &World, tilted: &str) -> Frame {
+    Frame::from_text(
+        format!("tilted {}, load {}", tilted, compute_load(w)),
+        &w.to_string(),
+    )
+    .color_char('O', Color::Yellow)
+    .color_char('#', Color::Grey)
+}
+
+fn spin_cycle(w: &mut World) {
+    tilt_north(w);
+    anim::frame(|| frame(w, "north"));
+    tilt_west(w);
+    anim::frame(|| frame(w, "west"));
+    tilt_south(w);
+    anim::frame(|| frame(w, "south"));
+    tilt_east(w);
+    anim::frame(|| frame(w, "east"));
+}
+
+fn spin_cycles(world: &World, cycles: usize) -> usize {
+    let (world, load) = skip_cycles(world.clone(), cycles, spin_cycle, World::clone, |w| {
+        compute_load(w) as i64
+    });
+    debug!("{}", world);
+    load as usize
 }
 
-fn main() {
-    let path = std::env::args().nth(1).expect("Missing file path argument");
-    let file_content = std::fs::read_to_string(path).expect("Unable to read file");
-    let mut world = parse_world(&file_content);
+pub struct Day;
 
-    let mut known_hashes = HashMap::new();
-    let mut shortcut_found = false;
-    let cycles = 1_000_000_000;
-    let mut cycle = 1;
-    loop {
+impl Solution for Day {
+    type Input = World;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(world: &Self::Input) -> Answer {
+        let mut world = world.clone();
         tilt_north(&mut world);
-        tilt_west(&mut world);
-        tilt_south(&mut world);
-        tilt_east(&mut world);
+        anim::frame(|| frame(&world, "north"));
+        compute_load(&world).into()
+    }
 
-        if !shortcut_found {
-            let h = hash(&world);
-            println!("cycle:{} hash:{} load:{}", cycle, h, compute_load(&world));
-            if let Some(last_cycle) = known_hashes.get(&h) {
-                println!(
-                    "Known hash spotted; last cycle: {}, current cycle: {}",
-                    last_cycle, cycle
-                );
-                shortcut_found = true;
+    fn part2(world: &Self::Input) -> Answer {
+        spin_cycles(world, 1_000_000_000).into()
+    }
+}
 
-                let remaining = cycles - cycle;
-                let skipped_cycles = cycle - last_cycle;
-                cycle += (remaining / skipped_cycles) * skipped_cycles;
-                println!("Skipping to cycle: {}", cycle);
-            } else {
-                known_hashes.insert(h, cycle);
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{assert_snapshot, check_example, parse_example};
 
-        if cycle == cycles {
-            break;
-        }
-        cycle += 1
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 136, 64);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut world = parse_example::<Day>(include_str!("../example.txt"));
+        let mut north = world.clone();
+        tilt_north(&mut north);
+        assert_snapshot!("north", north.to_string());
+
+        // the three cycles drawn in the puzzle
+        let cycles: Vec<String> = (0..3)
+            .map(|_| {
+                spin_cycle(&mut world);
+                world.to_string()
+            })
+            .collect();
+        assert_snapshot!("cycles", cycles.join("\n\n"));
     }
-    print_world(&world);
-    println!("load:{}", compute_load(&world));
 }
@@ -0,0 +1,137 @@
+use aoc_common::{debug, parse_field, Answer, ParseError, Solution};
+
+#[derive(Clone)]
+pub struct Lens {
+    box_name: String,
+    focal_length: usize,
+}
+
+pub struct Remove {
+    box_name: String,
+}
+
+pub enum Instruction {
+    Add(Lens),
+    Remove(Remove),
+}
+
+fn parse_instructions(s: &str) -> Result<Vec<Instruction>, ParseError> {
+    s.trim()
+        .split(',')
+        .map(|s| {
+            if let Some(stripped) = s.strip_suffix('-') {
+                Ok(Instruction::Remove(Remove {
+                    box_name: stripped.to_string(),
+                }))
+            } else {
+                let (box_name, focal_length) = s
+                    .split_once('=')
+                    .ok_or_else(|| ParseError::expected("label=focal length or label-", s))?;
+                Ok(Instruction::Add(Lens {
+                    box_name: box_name.to_string(),
+                    focal_length: parse_field(focal_length, "focal length")?,
+                }))
+            }
+        })
+        .collect()
+}
+
+fn hash(s: &str) -> usize {
+    let mut result = 0;
+    for char in s.chars() {
+        result += char as usize;
+        result *= 17;
+        result %= 256;
+    }
+    result
+}
+
+fn log_boxes(boxes: &[Vec<Lens>]) {
+    for (i, b) in boxes.iter().enumerate() {
+        if !b.is_empty() {
+            let lenses: String = b
+                .iter()
+                .map(|lens| format!(" [{} {}]", lens.box_name, lens.focal_length))
+                .collect();
+            debug!("Box {}:{}", i, lenses);
+        }
+    }
+}
+
+fn focusing_power(boxes: &[Vec<Lens>]) -> usize {
+    let mut ans = 0;
+    for (box_num, b) in boxes.iter().enumerate() {
+        for (lens_num, lens) in b.iter().enumerate() {
+            ans += (box_num + 1) * (lens_num + 1) * lens.focal_length;
+        }
+    }
+    ans
+}
+
+fn arrange_lenses(instructions: &[Instruction]) -> usize {
+    let mut boxes: Vec<Vec<Lens>> = vec![vec![]; 256];
+
+    for instruction in instructions.iter() {
+        match instruction {
+            Instruction::Remove(remove) => {
+                let i = hash(&remove.box_name);
+                let old_box = &boxes[i];
+                boxes[i] = old_box
+                    .iter()
+                    .filter(|b| b.box_name != remove.box_name)
+                    .cloned()
+                    .collect();
+            }
+            Instruction::Add(new_lens) => {
+                let index = hash(&new_lens.box_name);
+                let old_box = boxes.get_mut(index).unwrap();
+                let lens = old_box.iter_mut().find(|l| l.box_name == new_lens.box_name);
+                if let Some(lens) = lens {
+                    lens.focal_length = new_lens.focal_length;
+                } else {
+                    old_box.push(new_lens.clone());
+                }
+            }
+        }
+    }
+
+    log_boxes(&boxes);
+    focusing_power(&boxes)
+}
+
+pub struct InitSequence {
+    steps: Vec<String>,
+    instructions: Vec<Instruction>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = InitSequence;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(InitSequence {
+            steps: input.trim().split(',').map(|s| s.to_string()).collect(),
+            instructions: parse_instructions(input)?,
+        })
+    }
+
+    fn part1(sequence: &Self::Input) -> Answer {
+        sequence.steps.iter().map(|s| hash(s)).sum::<usize>().into()
+    }
+
+    fn part2(sequence: &Self::Input) -> Answer {
+        arrange_lenses(&sequence.instructions).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 1320, 145);
+    }
+}
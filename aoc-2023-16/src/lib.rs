@@ -0,0 +1,205 @@
+use aoc_common::{debug, parallel, Answer, ParseError, Solution};
+use aoc_grid::{Dir4, Grid, Pos, Tile};
+use aoc_image::{Image, BLACK, GREY, WHITE, YELLOW};
+use std::collections::HashSet;
+
+pub enum Mirror {
+    Slash,
+    Backslash,
+}
+
+pub enum Splitter {
+    Horizontal,
+    Vertical,
+}
+
+pub enum Item {
+    Mirror(Mirror),
+    Splitter(Splitter),
+    Empty,
+}
+
+type World = Grid<Item>;
+
+impl Tile for Item {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '/' => Some(Item::Mirror(Mirror::Slash)),
+            '\\' => Some(Item::Mirror(Mirror::Backslash)),
+            '|' => Some(Item::Splitter(Splitter::Vertical)),
+            '-' => Some(Item::Splitter(Splitter::Horizontal)),
+            '.' => Some(Item::Empty),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Item::Mirror(Mirror::Slash) => '/',
+            Item::Mirror(Mirror::Backslash) => '\\',
+            Item::Splitter(Splitter::Vertical) => '|',
+            Item::Splitter(Splitter::Horizontal) => '-',
+            Item::Empty => '.',
+        }
+    }
+}
+
+fn is_horizontal(direction: Dir4) -> bool {
+    matches!(direction, Dir4::Left | Dir4::Right)
+}
+
+/// Directions of the beam leaving a tile it entered in `direction`
+fn outgoing(item: &Item, direction: Dir4) -> Vec<Dir4> {
+    match item {
+        Item::Empty => vec![direction],
+        // horizontal move is split, vertical one passes through
+        Item::Splitter(Splitter::Vertical) if is_horizontal(direction) => {
+            vec![Dir4::Up, Dir4::Down]
+        }
+        Item::Splitter(Splitter::Horizontal) if !is_horizontal(direction) => {
+            vec![Dir4::Left, Dir4::Right]
+        }
+        Item::Splitter(_) => vec![direction],
+        // right -> up, left -> down, up -> right, down -> left
+        Item::Mirror(Mirror::Slash) if is_horizontal(direction) => vec![direction.turn_left()],
+        Item::Mirror(Mirror::Slash) => vec![direction.turn_right()],
+        // right -> down, left -> up, up -> left, down -> right
+        Item::Mirror(Mirror::Backslash) if is_horizontal(direction) => {
+            vec![direction.turn_right()]
+        }
+        Item::Mirror(Mirror::Backslash) => vec![direction.turn_left()],
+    }
+}
+
+fn ray_trace(world: &World, visited: &mut HashSet<(Pos, Dir4)>, pos: Pos, direction: Dir4) {
+    if !visited.insert((pos, direction)) {
+        return;
+    }
+
+    for new_direction in outgoing(&world[pos], direction) {
+        if let Some(new_pos) = world.offset(pos, new_direction.offset()) {
+            ray_trace(world, visited, new_pos, new_direction);
+        }
+    }
+}
+
+fn energized(world: &World, pos: Pos, direction: Dir4) -> HashSet<Pos> {
+    let mut visited = HashSet::new();
+    ray_trace(world, &mut visited, pos, direction);
+    visited.iter().map(|(pos, _)| *pos).collect()
+}
+
+fn evaluate(world: &World, pos: Pos, direction: Dir4) -> usize {
+    energized(world, pos, direction).len()
+}
+
+/// Every way of sending the beam in from the edge
+fn starts(world: &World) -> Vec<(Pos, Dir4)> {
+    let (rows, cols) = (world.height(), world.width());
+    let mut result = vec![];
+    for r in 0..rows {
+        result.push(((r, 0), Dir4::Right));
+        result.push(((r, cols - 1), Dir4::Left));
+    }
+    for c in 0..cols {
+        result.push(((0, c), Dir4::Down));
+        result.push(((rows - 1, c), Dir4::Up));
+    }
+    result
+}
+
+fn evaluate_all(world: &World) -> Vec<usize> {
+    parallel::map(starts(world), |(pos, direction)| {
+        evaluate(world, pos, direction)
+    })
+}
+
+/// Energized tiles in yellow, mirrors and splitters in grey or white when energized
+fn picture(world: &World, energized: &HashSet<Pos>) -> Image {
+    let mut image = Image::from_grid(world, |item| match item {
+        Item::Empty => BLACK,
+        _ => GREY,
+    });
+    for &pos in energized {
+        let color = match world[pos] {
+            Item::Empty => YELLOW,
+            _ => WHITE,
+        };
+        image.set(pos, color);
+    }
+    image
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = World;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let world: World = input.parse()?;
+        debug!("{}", world);
+        Ok(world)
+    }
+
+    fn part1(world: &Self::Input) -> Answer {
+        let energized = energized(world, (0, 0), Dir4::Right);
+        aoc_image::capture(|| picture(world, &energized));
+        energized.len().into()
+    }
+
+    fn part2(world: &Self::Input) -> Answer {
+        let energized_vals = evaluate_all(world);
+        let (best, max) = energized_vals
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, value)| **value)
+            .unwrap();
+        aoc_image::capture(|| {
+            let (pos, direction) = starts(world)[best];
+            picture(world, &energized(world, pos, direction))
+        });
+        (*max).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{check_example, parse_example};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 46, 51);
+    }
+
+    #[test]
+    fn test_picture() {
+        let world = parse_example::<Day>(include_str!("../example.txt"));
+        let image = picture(&world, &energized(&world, (0, 0), Dir4::Right));
+        let path = std::env::temp_dir().join(format!("aoc-2023-16-{}.png", std::process::id()));
+        aoc_image::write_png(&path, &image, 1).unwrap();
+        let image = aoc_image::read_png(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let count = |color| {
+            world
+                .positions()
+                .filter(|&pos| image.get(pos) == color)
+                .count()
+        };
+        assert_eq!(count(YELLOW) + count(WHITE), 46);
+        assert_eq!(image.get((0, 0)), YELLOW);
+        assert_eq!(image.get((0, 1)), WHITE);
+        assert_eq!(image.get((9, 9)), BLACK);
+    }
+
+    #[test]
+    fn test_threads() {
+        let world = parse_example::<Day>(include_str!("../example.txt"));
+        let sequential = evaluate_all(&world);
+        parallel::set_threads(4);
+        let threaded = evaluate_all(&world);
+        parallel::set_threads(1);
+        assert_eq!(threaded, sequential);
+    }
+}
@@ -0,0 +1,160 @@
+use aoc_common::{debug, Answer, ParseError, Solution};
+use aoc_grid::{Dir4, Grid, Pos};
+use aoc_image::{gradient, Image, BLACK, RED, WHITE};
+use aoc_search::dijkstra;
+use inline_colorization::{bg_green, bg_reset, color_black, color_reset};
+
+type Map = Grid<u32>;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+struct Node {
+    coord: Pos,
+    /// None before the first move
+    direction: Option<Dir4>,
+    count: usize,
+}
+
+/// How many blocks a crucible has to / can move in a single direction
+struct Crucible {
+    min: usize,
+    max: usize,
+}
+
+const CRUCIBLE: Crucible = Crucible { min: 1, max: 3 };
+const ULTRA_CRUCIBLE: Crucible = Crucible { min: 4, max: 10 };
+
+fn get_next_directions(
+    current_direction: Option<Dir4>,
+    count: usize,
+    crucible: &Crucible,
+) -> Vec<Dir4> {
+    let Some(current_direction) = current_direction else {
+        return Dir4::ALL.to_vec();
+    };
+    Dir4::ALL
+        .into_iter()
+        .filter(|&d| {
+            let is_opposite = d == current_direction.opposite();
+            let is_max = count >= crucible.max && d == current_direction;
+            let is_below_min = count < crucible.min && d != current_direction;
+            !(is_opposite || is_max || is_below_min)
+        })
+        .collect()
+}
+
+fn next_nodes(grid: &Map, u: &Node, crucible: &Crucible) -> Vec<(Node, u32)> {
+    get_next_directions(u.direction, u.count, crucible)
+        .into_iter()
+        .filter_map(|direction| {
+            let coord = grid.offset(u.coord, direction.offset())?;
+            let count = if Some(direction) == u.direction {
+                u.count + 1
+            } else {
+                1
+            };
+            let node = Node {
+                coord,
+                direction: Some(direction),
+                count,
+            };
+            Some((node, grid[coord]))
+        })
+        .collect()
+}
+
+/// The heat loss map with the path highlighted in green
+fn draw_path(grid: &Map, path: &[Pos]) -> String {
+    let rows: Vec<String> = grid
+        .rows()
+        .enumerate()
+        .map(|(r, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(c, val)| {
+                    if path.contains(&(r, c)) {
+                        format!("{color_black}{bg_green}{val}{bg_reset}{color_reset}")
+                    } else {
+                        val.to_string()
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
+
+/// Heat loss from dark (1) to light (9) with the path in red
+fn picture(grid: &Map, path: &[Pos]) -> Image {
+    let mut image = Image::from_grid(grid, |&heat| {
+        gradient(BLACK, WHITE, heat.saturating_sub(1) as f64 / 8.0)
+    });
+    for &pos in path {
+        image.set(pos, RED);
+    }
+    image
+}
+
+/// The least heat loss and the blocks of the path losing it
+fn best_path(grid: &Map, crucible: &Crucible) -> (u32, Vec<Pos>) {
+    let end = (grid.height() - 1, grid.width() - 1);
+    // Because you already start in the top-left block, you don't incur that block's heat loss
+    // unless you leave that block and then return to it.
+    let start = Node {
+        coord: (0, 0),
+        direction: None,
+        count: 0,
+    };
+    let search = dijkstra(
+        [start],
+        |u| next_nodes(grid, u, crucible),
+        |u| u.coord == end && u.count >= crucible.min,
+    );
+    let cost = search.goal_cost().expect("Shortest path not found!");
+    let path: Vec<Pos> = search.path().unwrap().iter().map(|n| n.coord).collect();
+    (cost, path)
+}
+
+fn min_heat_loss(grid: &Map, crucible: &Crucible) -> u32 {
+    let (cost, path) = best_path(grid, crucible);
+    debug!("{}", draw_path(grid, &path));
+    aoc_image::capture(|| picture(grid, &path));
+    cost
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_with(input, |c| c.to_digit(10))
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        min_heat_loss(grid, &CRUCIBLE).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        min_heat_loss(grid, &ULTRA_CRUCIBLE).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{assert_snapshot, check_example, parse_example};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 102, 94);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let grid = parse_example::<Day>(include_str!("../example.txt"));
+        for (name, crucible) in [("path", &CRUCIBLE), ("ultra_path", &ULTRA_CRUCIBLE)] {
+            let (_, path) = best_path(&grid, crucible);
+            assert_snapshot!(name, draw_path(&grid, &path));
+        }
+    }
+}
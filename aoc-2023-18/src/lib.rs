@@ -0,0 +1,125 @@
+use aoc_common::{scan::Template, Answer, ParseError, Solution};
+
+type Point = (i64, i64);
+
+pub struct Instruction {
+    direction: Point,
+    count: i64,
+}
+
+const DIG: Template = Template::new("{} {} (#{})");
+
+/// The instruction of part 1 and the one hidden in the color for part 2
+fn parse_line(line: &str) -> Result<(Instruction, Instruction), ParseError> {
+    let mut fields = DIG.scan(line)?;
+    let direction = match fields.text() {
+        "U" => (-1, 0),
+        "D" => (1, 0),
+        "L" => (0, -1),
+        "R" => (0, 1),
+        d => return Err(ParseError::expected("U, D, L or R", d)),
+    };
+    let count = fields.parse("count")?;
+    let instruction = Instruction { direction, count };
+
+    let color = fields.text();
+    if color.len() != 6 || !color.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ParseError::expected("6 hexadecimal digits", color));
+    }
+    let hex_direction = match &color[5..] {
+        "0" => (0, 1),
+        "1" => (1, 0),
+        "2" => (0, -1),
+        "3" => (-1, 0),
+        d => return Err(ParseError::expected("direction 0-3", d)),
+    };
+    // five hexadecimal digits always fit
+    let hex_count = i64::from_str_radix(&color[0..5], 16).unwrap();
+    let hex_instruction = Instruction {
+        direction: hex_direction,
+        count: hex_count,
+    };
+    Ok((instruction, hex_instruction))
+}
+
+fn get_points(instructions: &[Instruction]) -> Vec<Point> {
+    let mut result = vec![];
+
+    let mut current = (0, 0);
+    result.push(current);
+
+    for instruction in instructions {
+        current.0 += instruction.direction.0 * instruction.count;
+        current.1 += instruction.direction.1 * instruction.count;
+        result.push(current);
+    }
+
+    result
+}
+
+fn get_outer_points_count(instructions: &[Instruction]) -> i64 {
+    instructions.iter().map(|i| i.count).sum()
+}
+
+fn inner_area(points: &[Point]) -> i64 {
+    let n = points.len();
+    let mut result: i64 = 0;
+    for i in 0..points.len() {
+        result += (points[i].0 * points[(i + 1) % n].1) - (points[i].1 * points[(i + 1) % n].0);
+    }
+    (result / 2).abs()
+}
+
+fn outer_area(instructions: &[Instruction]) -> i64 {
+    get_outer_points_count(instructions) / 2 + 1
+}
+
+fn lagoon_area(instructions: &[Instruction]) -> i64 {
+    let points = get_points(instructions);
+    inner_area(&points) + outer_area(instructions)
+}
+
+/// The dig plan read both ways: as written and from the hexadecimal colours
+pub struct DigPlan {
+    instructions: Vec<Instruction>,
+    hex_instructions: Vec<Instruction>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = DigPlan;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (instructions, hex_instructions) = input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(parse_line)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+        Ok(DigPlan {
+            instructions,
+            hex_instructions,
+        })
+    }
+
+    fn part1(plan: &Self::Input) -> Answer {
+        lagoon_area(&plan.instructions).into()
+    }
+
+    fn part2(plan: &Self::Input) -> Answer {
+        lagoon_area(&plan.hex_instructions).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 62, 952408144115i64);
+    }
+}
@@ -0,0 +1,299 @@
+use aoc_common::{parse_field, scan::Template, Answer, ParseError, Solution};
+use aoc_interval::IntervalSet;
+use std::collections::HashMap;
+
+type Rating = HashMap<char, i64>;
+type Rules = HashMap<String, Vec<Rule>>;
+type StateSpace = HashMap<char, IntervalSet<i64>>;
+
+#[derive(Clone, PartialEq)]
+pub enum Output {
+    Accepted,
+    Rejected,
+}
+
+pub enum Then {
+    RuleName(String),
+    Output(Output),
+}
+
+pub struct Condition {
+    property: char,
+    value: i64,
+    then: Then,
+}
+
+pub enum Rule {
+    Gt(Condition),
+    Lt(Condition),
+    Fallback(Then),
+}
+
+struct RangeDivide {
+    result: IntervalSet<i64>,
+    complement: IntervalSet<i64>,
+}
+
+fn parse_then(s: &str) -> Then {
+    match s {
+        "A" => Then::Output(Output::Accepted),
+        "R" => Then::Output(Output::Rejected),
+        _ => Then::RuleName(s.into()),
+    }
+}
+
+fn parse_property(s: &str) -> Result<char, ParseError> {
+    s.chars()
+        .next()
+        .filter(|&c| s.len() == 1 && "xmas".contains(c))
+        .ok_or_else(|| ParseError::expected("x, m, a or s", s))
+}
+
+fn parse_condition(s: &str, condition_delimiter: char) -> Result<Condition, ParseError> {
+    let (prop, val_then) = s
+        .split_once(condition_delimiter)
+        .ok_or_else(|| ParseError::expected("condition", s))?;
+    let (val, then) = val_then
+        .split_once(':')
+        .ok_or_else(|| ParseError::expected("VALUE:WORKFLOW", val_then))?;
+    Ok(Condition {
+        property: parse_property(prop)?,
+        value: parse_field(val, "value")?,
+        then: parse_then(then),
+    })
+}
+
+fn parse_rule(s: &str) -> Result<(String, Vec<Rule>), ParseError> {
+    let (rule_name, rules) = s
+        .strip_suffix('}')
+        .and_then(|s| s.split_once('{'))
+        .ok_or_else(|| ParseError::expected("name{rules}", s))?;
+    let rules = rules
+        .split(',')
+        .map(|rule| {
+            if rule.contains('<') {
+                Ok(Rule::Lt(parse_condition(rule, '<')?))
+            } else if rule.contains('>') {
+                Ok(Rule::Gt(parse_condition(rule, '>')?))
+            } else {
+                Ok(Rule::Fallback(parse_then(rule)))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((rule_name.into(), rules))
+}
+
+const RATING: Template = Template::new("{x={},m={},a={},s={}}");
+
+fn parse_rating(s: &str) -> Result<Rating, ParseError> {
+    let mut fields = RATING.scan(s)?;
+    "xmas"
+        .chars()
+        .map(|c| Ok((c, fields.parse("rating")?)))
+        .collect()
+}
+
+fn process_rating(rating: &Rating, rules: &Rules, rule_name: &str) -> Output {
+    let then_to_output = |then: &Then| match then {
+        Then::Output(output) => output.clone(),
+        Then::RuleName(rule_name) => process_rating(rating, rules, rule_name),
+    };
+
+    let steps = &rules[rule_name];
+    for rule in steps {
+        match rule {
+            Rule::Gt(condition) => {
+                if rating[&condition.property] > condition.value {
+                    return then_to_output(&condition.then);
+                }
+            }
+            Rule::Lt(condition) => {
+                if rating[&condition.property] < condition.value {
+                    return then_to_output(&condition.then);
+                }
+            }
+            Rule::Fallback(then) => return then_to_output(then),
+        }
+    }
+
+    Output::Accepted
+}
+
+fn state_space_size(state_space: &StateSpace) -> usize {
+    state_space
+        .values()
+        .map(|range| range.len() as usize)
+        .product()
+}
+
+fn apply_gt(r: &IntervalSet<i64>, val: i64) -> RangeDivide {
+    let (complement, result) = r.split_at(val + 1);
+    RangeDivide { result, complement }
+}
+
+fn apply_lt(r: &IntervalSet<i64>, val: i64) -> RangeDivide {
+    let (result, complement) = r.split_at(val);
+    RangeDivide { result, complement }
+}
+
+fn count_accepted_combinations(rules: &Rules, rule_name: &str, state_space: StateSpace) -> usize {
+    let mut state_space = state_space;
+    let mut result = 0;
+    let set = &rules[rule_name];
+
+    for rule in set {
+        match rule {
+            Rule::Gt(condition) => {
+                let mut new_state_space = state_space.clone();
+                let division = apply_gt(&state_space[&condition.property], condition.value);
+                new_state_space.insert(condition.property, division.result);
+                state_space.insert(condition.property, division.complement);
+                match &condition.then {
+                    Then::Output(Output::Accepted) => result += state_space_size(&new_state_space),
+                    Then::Output(Output::Rejected) => {}
+                    Then::RuleName(rule_name) => {
+                        result += count_accepted_combinations(rules, rule_name, new_state_space);
+                    }
+                }
+            }
+            Rule::Lt(condition) => {
+                let mut new_state_space = state_space.clone();
+                let division = apply_lt(&state_space[&condition.property], condition.value);
+                new_state_space.insert(condition.property, division.result);
+                state_space.insert(condition.property, division.complement);
+                match &condition.then {
+                    Then::Output(Output::Accepted) => result += state_space_size(&new_state_space),
+                    Then::Output(Output::Rejected) => {}
+                    Then::RuleName(rule_name) => {
+                        result += count_accepted_combinations(rules, rule_name, new_state_space);
+                    }
+                }
+            }
+            Rule::Fallback(Then::Output(Output::Accepted)) => {
+                result += state_space_size(&state_space)
+            }
+            Rule::Fallback(Then::Output(Output::Rejected)) => {}
+            Rule::Fallback(Then::RuleName(rule_name)) => {
+                result += count_accepted_combinations(rules, rule_name, state_space.clone())
+            }
+        }
+    }
+    result
+}
+
+pub struct System {
+    rules: Rules,
+    ratings: Vec<Rating>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = System;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (rules, ratings) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new("missing blank line between workflows and ratings"))?;
+        let rules: Rules = rules.lines().map(parse_rule).collect::<Result<_, _>>()?;
+        let ratings: Vec<Rating> = ratings
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(parse_rating)
+            .collect::<Result<_, _>>()?;
+        Ok(System { rules, ratings })
+    }
+
+    fn part1(system: &Self::Input) -> Answer {
+        system
+            .ratings
+            .iter()
+            .filter(|rating| process_rating(rating, &system.rules, "in") == Output::Accepted)
+            .map(|rating| rating.values().sum::<i64>())
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part2(system: &Self::Input) -> Answer {
+        let state_space = "xmas"
+            .chars()
+            .map(|c| (c, IntervalSet::from(1..=4000)))
+            .collect();
+        count_accepted_combinations(&system.rules, "in", state_space).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_apply_gt() {
+        let r = IntervalSet::from(600..=3400i64);
+        let result = apply_gt(&r, 900);
+        assert_eq!(result.result, IntervalSet::from(901..=3400));
+        assert_eq!(result.complement, IntervalSet::from(600..=900));
+
+        let result = apply_gt(&r, 599);
+        assert_eq!(result.result, IntervalSet::from(600..=3400));
+        assert!(result.complement.is_empty());
+
+        let result = apply_gt(&r, 600);
+        assert_eq!(result.result, IntervalSet::from(601..=3400));
+        assert_eq!(result.complement, IntervalSet::from(600..=600));
+
+        let result = apply_gt(&r, 50);
+        assert_eq!(result.result, IntervalSet::from(600..=3400));
+        assert!(result.complement.is_empty());
+
+        let result = apply_gt(&r, 3399);
+        assert_eq!(result.result, IntervalSet::from(3400..=3400));
+        assert_eq!(result.complement, IntervalSet::from(600..=3399));
+
+        let result = apply_gt(&r, 3400);
+        assert!(result.result.is_empty());
+        assert_eq!(result.complement, IntervalSet::from(600..=3400));
+
+        let result = apply_gt(&r, 3800);
+        assert!(result.result.is_empty());
+        assert_eq!(result.complement, IntervalSet::from(600..=3400));
+    }
+
+    #[test]
+    fn test_apply_lt() {
+        let r = IntervalSet::from(600..=3400i64);
+        let result = apply_lt(&r, 900);
+        assert_eq!(result.result, IntervalSet::from(600..=899));
+        assert_eq!(result.complement, IntervalSet::from(900..=3400));
+
+        let result = apply_lt(&r, 600);
+        assert!(result.result.is_empty());
+        assert_eq!(result.complement, IntervalSet::from(600..=3400));
+
+        let result = apply_lt(&r, 601);
+        assert_eq!(result.result, IntervalSet::from(600..=600));
+        assert_eq!(result.complement, IntervalSet::from(601..=3400));
+
+        let result = apply_lt(&r, 50);
+        assert!(result.result.is_empty());
+        assert_eq!(result.complement, IntervalSet::from(600..=3400));
+
+        let result = apply_lt(&r, 3400);
+        assert_eq!(result.result, IntervalSet::from(600..=3399));
+        assert_eq!(result.complement, IntervalSet::from(3400..=3400));
+
+        let result = apply_lt(&r, 3401);
+        assert_eq!(result.result, IntervalSet::from(600..=3400));
+        assert!(result.complement.is_empty());
+
+        let result = apply_lt(&r, 3800);
+        assert_eq!(result.result, IntervalSet::from(600..=3400));
+        assert!(result.complement.is_empty());
+    }
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 19114, 167409079868000i64);
+    }
+}
@@ -0,0 +1,542 @@
+use aoc_common::{
+    assume::Assumption,
+    checkpoint::Checkpoint,
+    debug,
+    log::{self, Level},
+    Answer, ParseError, Solution,
+};
+use indicatif::ProgressBar;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FlipFlop {
+    state: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Conjunction {
+    inputs: HashMap<String, Pulse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Broadcaster {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum ModuleType {
+    FlipFlop(FlipFlop),
+    Conjunction(Conjunction),
+    Broadcaster(Broadcaster),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Module {
+    name: String,
+    kind: ModuleType,
+    outputs: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+enum Pulse {
+    Low,
+    High,
+}
+
+struct IO {
+    pulse: Pulse,
+    src: String,
+}
+
+struct Stats {
+    low_count: usize,
+    high_count: usize,
+    rx_low: bool,
+}
+
+trait ProcessPulse {
+    fn process(&mut self, io: &IO) -> Option<Pulse>;
+}
+
+impl ProcessPulse for Broadcaster {
+    fn process(&mut self, io: &IO) -> Option<Pulse> {
+        Some(io.pulse.clone())
+    }
+}
+
+impl ProcessPulse for FlipFlop {
+    fn process(&mut self, io: &IO) -> Option<Pulse> {
+        if io.pulse == Pulse::High {
+            // If a flip-flop module receives a high pulse, it is ignored and nothing happens
+            None
+        } else if !self.state {
+            // If it was off, it turns on and sends a high pulse
+            self.state = true;
+            Some(Pulse::High)
+        } else {
+            // If it was on, it turns off and sends a low pulse.
+            self.state = false;
+            Some(Pulse::Low)
+        }
+    }
+}
+
+impl ProcessPulse for Conjunction {
+    fn process(&mut self, io: &IO) -> Option<Pulse> {
+        // When a pulse is received, the conjunction module first updates its memory for that input.
+        self.inputs.insert(io.src.clone(), io.pulse.clone());
+
+        if self.inputs.values().all(|p| p == &Pulse::High) {
+            // Then, if it remembers high pulses for all inputs, it sends a low pulse;
+            Some(Pulse::Low)
+        } else {
+            // otherwise, it sends a high pulse.
+            Some(Pulse::High)
+        }
+    }
+}
+
+type Machine = HashMap<String, Module>;
+
+/// Button presses done so far and the machine after them
+#[derive(Serialize, Deserialize)]
+struct Presses {
+    done: usize,
+    machine: Machine,
+}
+
+fn parse_machine(s: &str) -> Result<Machine, ParseError> {
+    let mut machine = HashMap::new();
+    for line in s.lines().filter(|line| !line.is_empty()) {
+        let (module, outputs) = line
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::expected("MODULE -> OUTPUTS", line))?;
+        let outputs: Vec<String> = outputs.split(',').map(|o| o.trim().to_string()).collect();
+        let module = match module.chars().next() {
+            Some('%') => Module {
+                // they are initially off
+                kind: ModuleType::FlipFlop(FlipFlop { state: false }),
+                name: module.chars().skip(1).collect(),
+                outputs,
+            },
+            Some('&') => Module {
+                kind: ModuleType::Conjunction(Conjunction {
+                    inputs: HashMap::new(),
+                }),
+                name: module.chars().skip(1).collect(),
+                outputs,
+            },
+            _ if module == "broadcaster" => Module {
+                kind: ModuleType::Broadcaster(Broadcaster {}),
+                name: module.chars().collect(),
+                outputs,
+            },
+            _ => {
+                return Err(ParseError::expected(
+                    "%flip-flop, &conjunction or broadcaster",
+                    module,
+                ))
+            }
+        };
+        machine.insert(module.name.clone(), module);
+    }
+
+    let mut inputs: HashMap<String, Vec<String>> = HashMap::new();
+    for (module_name, module) in machine.iter() {
+        for output in module.outputs.iter() {
+            if let Some(other_module) = machine.get(output) {
+                if let ModuleType::Conjunction(_) = other_module.kind {
+                    inputs
+                        .entry(other_module.name.clone())
+                        .or_default()
+                        .push(module_name.clone());
+                }
+            }
+        }
+    }
+    for (module_name, inputs) in inputs {
+        if let Some(module) = machine.get_mut(&module_name) {
+            if let ModuleType::Conjunction(c) = &mut module.kind {
+                c.inputs = inputs
+                    .into_iter()
+                    // they initially default to remembering a low pulse for each input
+                    .map(|input| (input, Pulse::Low))
+                    .collect()
+            }
+        }
+    }
+    Ok(machine)
+}
+
+fn push_button(machine: &mut Machine) -> Stats {
+    // When you push the button, a single low pulse is sent directly to the broadcaster module
+    let mut low_count = 1;
+    let mut high_count = 0;
+    let mut rx_low = false;
+    let mut queue = VecDeque::new();
+    queue.push_back((
+        IO {
+            pulse: Pulse::Low,
+            src: String::from("button"),
+        },
+        String::from("broadcaster"),
+    ));
+
+    while let Some((queue_item, dest)) = queue.pop_front() {
+        if let Some(module) = machine.get_mut(&dest) {
+            let output_pulse = match &mut module.kind {
+                ModuleType::Broadcaster(b) => b.process(&queue_item),
+                ModuleType::Conjunction(c) => c.process(&queue_item),
+                ModuleType::FlipFlop(f) => f.process(&queue_item),
+            };
+            // if the output pulse is something
+            if let Some(output_pulse) = output_pulse {
+                // send it to each output
+                for new_dest in module.outputs.iter() {
+                    queue.push_back((
+                        IO {
+                            pulse: output_pulse.clone(),
+                            src: module.name.clone(),
+                        },
+                        new_dest.clone(),
+                    ));
+                    //print!("{}", module.name);
+                    if output_pulse == Pulse::High {
+                        high_count += 1;
+                        //print!(" -high-> ");
+                    } else {
+                        low_count += 1;
+                        //print!(" -low-> ");
+                    }
+                    //println!("{}", new_dest);
+
+                    if output_pulse == Pulse::Low && new_dest == "rx" {
+                        rx_low = true;
+                    }
+                }
+            }
+        }
+    }
+
+    Stats {
+        low_count,
+        high_count,
+        rx_low,
+    }
+}
+
+fn check_rx(machine: &Machine) -> Result<(), String> {
+    match machine
+        .values()
+        .any(|m| m.outputs.iter().any(|o| o == "rx"))
+    {
+        true => Ok(()),
+        false => Err(String::from("no module sends pulses to rx")),
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Machine;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let machine = parse_machine(input)?;
+        for (module_name, module) in machine.iter() {
+            debug!("{}: {:?}", module_name, module);
+        }
+        Ok(machine)
+    }
+
+    fn part1(machine: &Self::Input) -> Answer {
+        let mut machine = machine.clone();
+        let stats = (1..=1000).map(|_| push_button(&mut machine)).fold(
+            Stats {
+                high_count: 0,
+                low_count: 0,
+                rx_low: false,
+            },
+            |acc, x| Stats {
+                high_count: acc.high_count + x.high_count,
+                low_count: acc.low_count + x.low_count,
+                rx_low: acc.rx_low || x.rx_low,
+            },
+        );
+        (stats.low_count * stats.high_count).into()
+    }
+
+    fn part2(machine: &Self::Input) -> Answer {
+        let (mut checkpoint, resumed) = Checkpoint::start("button presses");
+        let mut presses = resumed.unwrap_or_else(|| Presses {
+            done: 0,
+            machine: machine.clone(),
+        });
+        let progress = match log::enabled(Level::Warn) {
+            true => ProgressBar::new_spinner(),
+            false => ProgressBar::hidden(),
+        };
+        progress.set_position(presses.done as u64);
+        loop {
+            let stats = push_button(&mut presses.machine);
+            presses.done += 1;
+            progress.inc(1);
+            if stats.rx_low {
+                checkpoint.finish();
+                return presses.done.into();
+            }
+            checkpoint.save(&presses);
+        }
+    }
+
+    fn assumptions(machine: &Self::Input) -> Vec<Assumption> {
+        vec![Assumption::part2(
+            "there is an rx module to count the button presses for",
+            check_rx(machine),
+        )]
+    }
+}
+
+/// Random valid inputs, for the differential tests and `aoc gen`
+#[cfg(any(test, feature = "generate"))]
+pub mod generate {
+    use proptest::prelude::*;
+
+    /// Kind and outputs of every module; the broadcaster comes first and the
+    /// index after the last module stands for rx.
+    pub type Graph = Vec<(char, Vec<usize>)>;
+
+    /// The broadcaster and up to `size` modules. Pulses only go to modules
+    /// further down the list, so that every push of the button ends.
+    pub fn graph(size: usize) -> impl Strategy<Value = Graph> {
+        prop::collection::vec(prop::sample::select(vec!['%', '&']), 1..=size)
+            .prop_flat_map(|kinds| {
+                let n = kinds.len();
+                let outputs: Vec<_> = (0..=n)
+                    .map(|i| prop::collection::btree_set(i + 1..=n + 1, 1..=3))
+                    .collect();
+                (Just(kinds), outputs)
+            })
+            .prop_map(|(kinds, outputs)| {
+                std::iter::once('b')
+                    .chain(kinds)
+                    .zip(outputs)
+                    .map(|(kind, outputs)| (kind, outputs.into_iter().collect()))
+                    .collect()
+            })
+    }
+
+    fn name(graph: &Graph, i: usize) -> String {
+        match i {
+            0 => String::from("broadcaster"),
+            i if i == graph.len() => String::from("rx"),
+            i => format!("m{}", i),
+        }
+    }
+
+    pub fn configuration(graph: &Graph) -> String {
+        graph
+            .iter()
+            .enumerate()
+            .map(|(i, (kind, outputs))| {
+                let outputs: Vec<_> = outputs.iter().map(|&o| name(graph, o)).collect();
+                let kind = if i == 0 {
+                    String::new()
+                } else {
+                    kind.to_string()
+                };
+                format!("{}{} -> {}", kind, name(graph, i), outputs.join(", "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The configuration of [`graph`].
+    pub fn input(size: usize) -> impl Strategy<Value = String> {
+        graph(size).prop_map(|graph| configuration(&graph))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::{configuration, graph, Graph};
+    use crate::*;
+    use aoc_common::{check_part1, parse_example};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_flip_flop() {
+        let mut flip_flop = FlipFlop { state: false };
+
+        let out = flip_flop.process(&crate::IO {
+            pulse: crate::Pulse::High,
+            src: String::from("a"),
+        });
+        assert!(out.is_none());
+
+        let out = flip_flop.process(&crate::IO {
+            pulse: crate::Pulse::Low,
+            src: String::from("a"),
+        });
+        assert_eq!(out, Some(Pulse::High));
+        assert!(flip_flop.state);
+
+        let out = flip_flop.process(&crate::IO {
+            pulse: crate::Pulse::Low,
+            src: String::from("a"),
+        });
+        assert_eq!(out, Some(Pulse::Low));
+        assert!(!flip_flop.state);
+    }
+
+    #[test]
+    fn test_conjunction() {
+        let mut conjunction = Conjunction {
+            inputs: HashMap::from([
+                (String::from("a"), Pulse::Low),
+                (String::from("b"), Pulse::Low),
+            ]),
+        };
+
+        let out = conjunction.process(&crate::IO {
+            pulse: crate::Pulse::High,
+            src: String::from("a"),
+        });
+        assert_eq!(out, Some(Pulse::High));
+
+        let out = conjunction.process(&crate::IO {
+            pulse: crate::Pulse::High,
+            src: String::from("b"),
+        });
+        assert_eq!(out, Some(Pulse::Low));
+
+        let out = conjunction.process(&crate::IO {
+            pulse: crate::Pulse::High,
+            src: String::from("a"),
+        });
+        assert_eq!(out, Some(Pulse::Low));
+
+        let out = conjunction.process(&crate::IO {
+            pulse: crate::Pulse::Low,
+            src: String::from("a"),
+        });
+        assert_eq!(out, Some(Pulse::High));
+    }
+
+    #[test]
+    fn test_push_button() {
+        let mut machine =
+            parse_machine("broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a")
+                .unwrap();
+        let stats = push_button(&mut machine);
+        assert_eq!(stats.high_count, 4);
+        assert_eq!(stats.low_count, 8);
+
+        let mut machine =
+            parse_machine("broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> output")
+                .unwrap();
+        let stats = (1..=1000).map(|_| push_button(&mut machine)).fold(
+            Stats {
+                high_count: 0,
+                low_count: 0,
+                rx_low: false,
+            },
+            |acc, x| Stats {
+                high_count: acc.high_count + x.high_count,
+                low_count: acc.low_count + x.low_count,
+                rx_low: acc.rx_low || x.rx_low,
+            },
+        );
+        assert_eq!(stats.high_count, 2750);
+        assert_eq!(stats.low_count, 4250);
+    }
+
+    #[test]
+    fn test_example() {
+        // there is no example for part 2, it needs an rx module
+        check_part1::<Day>(include_str!("../example.txt"), 32000000);
+        check_part1::<Day>(include_str!("../example2.txt"), 11687500);
+    }
+
+    #[test]
+    fn test_assumptions() {
+        let machine = parse_example::<Day>(include_str!("../example.txt"));
+        assert!(check_rx(&machine).is_err());
+    }
+
+    /// The rules of the puzzle written down once more, with plain vectors.
+    struct Reference<'a> {
+        graph: &'a Graph,
+        flip_flops: Vec<bool>,
+        /// Last pulse from every module to every module, true if high
+        memory: Vec<Vec<bool>>,
+        inputs: Vec<Vec<usize>>,
+    }
+
+    impl<'a> Reference<'a> {
+        fn new(graph: &'a Graph) -> Self {
+            let n = graph.len() + 1;
+            let mut inputs = vec![vec![]; n];
+            for (src, (_, outputs)) in graph.iter().enumerate() {
+                for &dest in outputs {
+                    inputs[dest].push(src);
+                }
+            }
+            Reference {
+                graph,
+                flip_flops: vec![false; n],
+                memory: vec![vec![false; n]; n],
+                inputs,
+            }
+        }
+
+        /// (low pulses, high pulses, whether rx got a low pulse)
+        fn push(&mut self) -> (usize, usize, bool) {
+            let rx = self.graph.len();
+            let (mut low, mut high, mut rx_low) = (0, 0, false);
+            let mut queue = VecDeque::from([(usize::MAX, 0, false)]);
+            while let Some((src, dest, pulse)) = queue.pop_front() {
+                if pulse {
+                    high += 1;
+                } else {
+                    low += 1;
+                    rx_low |= dest == rx;
+                }
+                if dest == rx {
+                    continue;
+                }
+                let output = match self.graph[dest].0 {
+                    'b' => pulse,
+                    '%' if pulse => continue,
+                    '%' => {
+                        self.flip_flops[dest] = !self.flip_flops[dest];
+                        self.flip_flops[dest]
+                    }
+                    _ => {
+                        self.memory[dest][src] = pulse;
+                        !self.inputs[dest].iter().all(|&i| self.memory[dest][i])
+                    }
+                };
+                for &next in &self.graph[dest].1 {
+                    queue.push_back((dest, next, output));
+                }
+            }
+            (low, high, rx_low)
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn prop_against_reference(graph in graph(8)) {
+            let mut machine = parse_machine(&configuration(&graph)).unwrap();
+            let mut reference = Reference::new(&graph);
+            for _ in 0..20 {
+                let stats = push_button(&mut machine);
+                prop_assert_eq!(
+                    (stats.low_count, stats.high_count, stats.rx_low),
+                    reference.push()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Frames of the simulations, for watching them step by step.
+//!
+//! The solvers describe what the simulation looks like and the runner decides
+//! what to do with it, like the log level. Without a sink installed the
+//! frames are not even built.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Grey,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Option<Color>,
+}
+
+/// One picture of a simulation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub caption: String,
+    pub rows: Vec<Vec<Cell>>,
+    /// Cell the viewport keeps in sight, as (row, column)
+    pub focus: Option<(usize, usize)>,
+}
+
+impl Frame {
+    /// A frame with one row per line of `text`, e.g. a `Grid` printed with `to_string`.
+    pub fn from_text(caption: impl Into<String>, text: &str) -> Self {
+        let rows = text
+            .lines()
+            .map(|line| line.chars().map(|ch| Cell { ch, color: None }).collect())
+            .collect();
+        Frame {
+            caption: caption.into(),
+            rows,
+            focus: None,
+        }
+    }
+
+    pub fn focus(mut self, row: usize, col: usize) -> Self {
+        self.focus = Some((row, col));
+        self
+    }
+
+    /// Colours one cell; cells outside of the frame are ignored.
+    pub fn color(mut self, row: usize, col: usize, color: Color) -> Self {
+        if let Some(cell) = self.rows.get_mut(row).and_then(|r| r.get_mut(col)) {
+            cell.color = Some(color);
+        }
+        self
+    }
+
+    /// Colours every occurrence of `ch`.
+    pub fn color_char(mut self, ch: char, color: Color) -> Self {
+        for cell in self.rows.iter_mut().flatten().filter(|cell| cell.ch == ch) {
+            cell.color = Some(color);
+        }
+        self
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+}
+
+/// Receiver of the frames, e.g. a terminal player.
+pub trait Sink: Send {
+    /// Shows `frame`; returns false once no more frames are wanted.
+    fn frame(&mut self, frame: &Frame) -> bool;
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SINK: Mutex<Option<Box<dyn Sink>>> = Mutex::new(None);
+
+pub fn set_sink(sink: Box<dyn Sink>) {
+    *SINK.lock().unwrap() = Some(sink);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Removes the sink, so that it can be finished by dropping it.
+pub fn take_sink() -> Option<Box<dyn Sink>> {
+    ENABLED.store(false, Ordering::Relaxed);
+    SINK.lock().unwrap().take()
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Sends the frame built by `make` to the sink, if there is one.
+pub fn frame(make: impl FnOnce() -> Frame) {
+    if !enabled() {
+        return;
+    }
+    let frame = make();
+    if let Some(sink) = SINK.lock().unwrap().as_mut() {
+        if !sink.frame(&frame) {
+            ENABLED.store(false, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::anim::*;
+    use std::sync::Arc;
+
+    struct Collect(Arc<Mutex<Vec<Frame>>>, usize);
+
+    impl Sink for Collect {
+        fn frame(&mut self, frame: &Frame) -> bool {
+            let mut frames = self.0.lock().unwrap();
+            frames.push(frame.clone());
+            frames.len() < self.1
+        }
+    }
+
+    #[test]
+    fn test_frames() {
+        let frame = Frame::from_text("round 1", "#.\n.#.\n")
+            .color_char('#', Color::Red)
+            .color(0, 1, Color::Blue)
+            .color(5, 5, Color::Blue)
+            .focus(1, 2);
+        assert_eq!((frame.height(), frame.width()), (2, 3));
+        assert_eq!(frame.rows[0][0].color, Some(Color::Red));
+        assert_eq!(frame.rows[0][1].color, Some(Color::Blue));
+        assert_eq!(frame.rows[1][0].color, None);
+        assert_eq!(frame.rows[1][1].color, Some(Color::Red));
+
+        let frames = Arc::new(Mutex::new(vec![]));
+        set_sink(Box::new(Collect(frames.clone(), 2)));
+        for i in 0..5 {
+            crate::anim::frame(|| Frame::from_text(format!("round {}", i), "#"));
+        }
+        assert!(!enabled());
+        assert!(take_sink().is_some());
+        let captions: Vec<_> = frames
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|f| f.caption.clone())
+            .collect();
+        assert_eq!(captions, ["round 0", "round 1"]);
+    }
+}
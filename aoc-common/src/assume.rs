@@ -0,0 +1,100 @@
+//! Properties of the input that a solution relies on although the puzzle
+//! does not promise them, e.g. a particular shape of the cube net.
+//!
+//! A day declares them in [`Solution::assumptions`](crate::Solution::assumptions);
+//! [`solve`](crate::solve) warns about the broken ones and skips the parts
+//! relying on them instead of giving a wrong answer or panicking.
+
+use crate::warn;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    Part1,
+    Part2,
+    Both,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assumption {
+    /// Completes "the input breaks the assumption that ...".
+    pub description: &'static str,
+    pub parts: Parts,
+    /// How the input breaks the assumption.
+    pub violation: Option<String>,
+}
+
+impl Assumption {
+    pub fn new(description: &'static str, parts: Parts, check: Result<(), String>) -> Self {
+        Assumption {
+            description,
+            parts,
+            violation: check.err(),
+        }
+    }
+
+    pub fn part1(description: &'static str, check: Result<(), String>) -> Self {
+        Assumption::new(description, Parts::Part1, check)
+    }
+
+    pub fn part2(description: &'static str, check: Result<(), String>) -> Self {
+        Assumption::new(description, Parts::Part2, check)
+    }
+
+    pub fn both(description: &'static str, check: Result<(), String>) -> Self {
+        Assumption::new(description, Parts::Both, check)
+    }
+
+    pub fn holds(&self) -> bool {
+        self.violation.is_none()
+    }
+
+    /// Whether the 1-based `part` relies on the assumption.
+    pub fn covers(&self, part: usize) -> bool {
+        matches!(
+            (self.parts, part),
+            (Parts::Both, _) | (Parts::Part1, 1) | (Parts::Part2, 2)
+        )
+    }
+}
+
+/// Warns about every broken assumption and returns which parts can run.
+pub fn check(assumptions: &[Assumption]) -> [bool; 2] {
+    let mut runs = [true; 2];
+    for assumption in assumptions {
+        let Some(violation) = &assumption.violation else {
+            continue;
+        };
+        let parts = match assumption.parts {
+            Parts::Part1 => "part 1",
+            Parts::Part2 => "part 2",
+            Parts::Both => "both parts",
+        };
+        warn!(
+            "skipping {}, the input breaks the assumption that {}: {}",
+            parts, assumption.description, violation
+        );
+        for (part, runs) in (1..).zip(runs.iter_mut()) {
+            *runs &= !assumption.covers(part);
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assume::*;
+
+    #[test]
+    fn test_check() {
+        let holds = Assumption::both("it holds", Ok(()));
+        let broken = Assumption::part2("it holds", Err("it does not".into()));
+        assert!(holds.holds());
+        assert!(!broken.holds());
+        assert!(broken.covers(2) && !broken.covers(1));
+
+        assert_eq!(check(&[]), [true, true]);
+        assert_eq!(check(&[holds.clone(), broken]), [true, false]);
+        let broken = Assumption::both("it holds", Err("it does not".into()));
+        assert_eq!(check(&[broken, holds]), [false, false]);
+    }
+}
@@ -0,0 +1,160 @@
+//! Progress of long searches that survives an interrupted run.
+//!
+//! A search saves its state now and then under a name unique within the day
+//! and asks for the saved state when it starts. The runner decides where the
+//! states are kept and whether an earlier run is resumed; without a store
+//! installed nothing is saved and every search starts from scratch.
+
+use crate::{info, warn};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Keeper of the saved states, e.g. a file per day.
+pub trait Store: Send {
+    /// The state saved under `name` if the run resumes an earlier one.
+    fn load(&mut self, name: &str) -> Option<Value>;
+    fn save(&mut self, name: &str, state: Value);
+    /// Forgets the state of a finished search.
+    fn clear(&mut self, name: &str);
+}
+
+struct Installed {
+    store: Box<dyn Store>,
+    every: Duration,
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static STORE: Mutex<Option<Installed>> = Mutex::new(None);
+
+/// Installs `store`; a search saves its state at most once per `every`.
+pub fn set_store(store: Box<dyn Store>, every: Duration) {
+    *STORE.lock().unwrap() = Some(Installed { store, every });
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn take_store() -> Option<Box<dyn Store>> {
+    ENABLED.store(false, Ordering::Relaxed);
+    STORE
+        .lock()
+        .unwrap()
+        .take()
+        .map(|installed| installed.store)
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Saves the state of one search.
+pub struct Checkpoint<T> {
+    name: &'static str,
+    last: Instant,
+    state: PhantomData<T>,
+}
+
+impl<T: Serialize + DeserializeOwned> Checkpoint<T> {
+    /// Starts the search `name`, with its saved state if there is one to resume.
+    pub fn start(name: &'static str) -> (Self, Option<T>) {
+        let checkpoint = Checkpoint {
+            name,
+            last: Instant::now(),
+            state: PhantomData,
+        };
+        if !enabled() {
+            return (checkpoint, None);
+        }
+        let saved = STORE
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|installed| installed.store.load(name));
+        let state = saved.and_then(|saved| match serde_json::from_value(saved) {
+            Ok(state) => {
+                info!("resuming {} from its checkpoint", name);
+                Some(state)
+            }
+            Err(e) => {
+                warn!(
+                    "starting {} over, its checkpoint is unreadable: {}",
+                    name, e
+                );
+                None
+            }
+        });
+        (checkpoint, state)
+    }
+
+    /// Saves `state` unless the last save is too recent.
+    pub fn save(&mut self, state: &T) {
+        if !enabled() {
+            return;
+        }
+        let mut installed = STORE.lock().unwrap();
+        let Some(installed) = installed.as_mut() else {
+            return;
+        };
+        if self.last.elapsed() < installed.every {
+            return;
+        }
+        match serde_json::to_value(state) {
+            Ok(state) => installed.store.save(self.name, state),
+            Err(e) => warn!("cannot save the checkpoint of {}: {}", self.name, e),
+        }
+        self.last = Instant::now();
+    }
+
+    /// Forgets the saved state once the search is complete.
+    pub fn finish(self) {
+        if let Some(installed) = STORE.lock().unwrap().as_mut() {
+            installed.store.clear(self.name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::checkpoint::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    #[derive(Clone, Default)]
+    struct Memory(Arc<Mutex<HashMap<String, Value>>>);
+
+    impl Store for Memory {
+        fn load(&mut self, name: &str) -> Option<Value> {
+            self.0.lock().unwrap().get(name).cloned()
+        }
+
+        fn save(&mut self, name: &str, state: Value) {
+            self.0.lock().unwrap().insert(name.to_string(), state);
+        }
+
+        fn clear(&mut self, name: &str) {
+            self.0.lock().unwrap().remove(name);
+        }
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        let (mut checkpoint, state) = Checkpoint::<(u32, String)>::start("search");
+        assert_eq!(state, None);
+        checkpoint.save(&(1, "nowhere to save".into()));
+
+        let memory = Memory::default();
+        set_store(Box::new(memory.clone()), Duration::ZERO);
+        checkpoint.save(&(2, "saved".into()));
+        let (checkpoint, state) = Checkpoint::<(u32, String)>::start("search");
+        assert_eq!(state, Some((2, "saved".into())));
+        // a state of another shape is not resumed
+        assert_eq!(Checkpoint::<Vec<u32>>::start("search").1, None);
+
+        checkpoint.finish();
+        assert!(memory.0.lock().unwrap().is_empty());
+        assert!(take_store().is_some());
+        assert!(!enabled());
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::hash_map::{Entry, HashMap};
+use std::hash::Hash;
+
+/// Runs `step` on `state` `target` times, skipping whole cycles once a state
+/// repeats.
+///
+/// States are compared by the exact value returned by `key`. `metric` is read
+/// after each step. It is assumed to grow by the same amount on every cycle.
+/// Returns the final state and its metric, including the growth over the
+/// skipped cycles.
+pub fn skip_cycles<S, K: Hash + Eq>(
+    mut state: S,
+    target: usize,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> i64,
+) -> (S, i64) {
+    let mut seen = HashMap::from([(key(&state), (0, metric(&state)))]);
+    let mut skipped = 0;
+    let mut i = 0;
+    while i < target {
+        step(&mut state);
+        i += 1;
+        if seen.is_empty() {
+            continue;
+        }
+        let m = metric(&state);
+        match seen.entry(key(&state)) {
+            Entry::Occupied(entry) => {
+                let (start, start_metric) = *entry.get();
+                let len = i - start;
+                let cycles = (target - i) / len;
+                i += cycles * len;
+                skipped = cycles as i64 * (m - start_metric);
+                seen.clear();
+            }
+            Entry::Vacant(entry) => {
+                entry.insert((i, m));
+            }
+        }
+    }
+    let m = metric(&state);
+    (state, m + skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cycle::*;
+
+    #[test]
+    fn test_skip_cycles() {
+        // (position on a cycle of 4 after a 3 step lead-in, laps done)
+        let step = |s: &mut (usize, i64)| {
+            s.0 += 1;
+            if s.0 == 7 {
+                s.0 = 3;
+                s.1 += 1;
+            }
+        };
+        let (state, laps) = skip_cycles((0, 0), 1_000_000_003, step, |s| s.0, |s| s.1);
+        assert_eq!(state.0, 3);
+        assert_eq!(laps, 250_000_000);
+
+        let (state, laps) = skip_cycles((0, 0), 2, step, |s| s.0, |s| s.1);
+        assert_eq!(state, (2, 0));
+        assert_eq!(laps, 0);
+    }
+}
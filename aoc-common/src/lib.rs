@@ -0,0 +1,359 @@
+use std::cell::Cell;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use assume::Assumption;
+
+pub mod anim;
+pub mod assume;
+pub mod checkpoint;
+pub mod cycle;
+pub mod log;
+pub mod parallel;
+pub mod scan;
+pub mod snapshot;
+
+/// Answer of a single puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// The part has no puzzle (e.g. day 25 part 2).
+    Empty,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Empty => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                match i64::try_from(value) {
+                    Ok(n) => Answer::Number(n),
+                    Err(_) => Answer::Text(value.to_string()),
+                }
+            }
+        })*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Error of any puzzle parser.
+///
+/// Parsers report the offending part of the input with [`ParseError::expected`];
+/// the line and column are filled in later by [`ParseError::locate`] as long as
+/// the reported snippet is a slice of the original input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// 1-based line of the offending snippet.
+    pub line: Option<usize>,
+    /// 1-based column (in characters) of the offending snippet.
+    pub column: Option<usize>,
+    pub snippet: Option<String>,
+    pub expected: Option<String>,
+    address: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            line: None,
+            column: None,
+            snippet: None,
+            expected: None,
+            address: None,
+        }
+    }
+
+    /// `found` should be a slice of the parsed text so that it can be located.
+    pub fn expected(expected: impl Into<String>, found: &str) -> Self {
+        ParseError {
+            expected: Some(expected.into()),
+            ..ParseError::new("").found(found)
+        }
+    }
+
+    pub fn found(mut self, found: &str) -> Self {
+        self.snippet = Some(found.to_string());
+        self.address = Some(found.as_ptr() as usize);
+        self
+    }
+
+    fn found_if_unknown(self, found: &str) -> Self {
+        match self.snippet {
+            Some(_) => self,
+            None => self.found(found),
+        }
+    }
+
+    /// Fills in the line and column if the snippet lies within `source`.
+    ///
+    /// Locating again against an enclosing text makes the position absolute.
+    pub fn locate(mut self, source: &str) -> Self {
+        let start = source.as_ptr() as usize;
+        let Some(offset) = self
+            .address
+            .filter(|&a| a >= start && a <= start + source.len())
+            .map(|a| a - start)
+        else {
+            return self;
+        };
+        if !source.is_char_boundary(offset) {
+            return self;
+        }
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        self.line = Some(before.matches('\n').count() + 1);
+        self.column = Some(before[line_start..].chars().count() + 1);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "parse error")?;
+        if let Some(line) = self.line {
+            write!(f, " at line {}", line)?;
+            if let Some(column) = self.column {
+                write!(f, ", column {}", column)?;
+            }
+        }
+
+        let mut details = Vec::new();
+        if !self.message.is_empty() {
+            details.push(self.message.clone());
+        }
+        if let Some(expected) = &self.expected {
+            details.push(format!("expected {}", expected));
+        }
+        if let Some(snippet) = &self.snippet {
+            details.push(format!("found {:?}", snippet));
+        }
+        write!(f, ": {}", details.join(", "))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `s` with `FromStr`, reporting `s` itself as the offending snippet.
+pub fn parse_field<T: FromStr>(s: &str, expected: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::expected(expected, s))
+}
+
+thread_local! {
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// In strict mode the record parsers reject the whole input on the first bad
+/// record; otherwise bad records are skipped with a warning.
+pub fn set_strict(strict: bool) {
+    STRICT.with(|s| s.set(strict));
+}
+
+pub fn is_strict() -> bool {
+    STRICT.with(|s| s.get())
+}
+
+fn parse_records<'a, T>(
+    input: &'a str,
+    records: impl Iterator<Item = &'a str>,
+) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    let mut result = Vec::new();
+    for record in records.filter(|r| !r.trim().is_empty()) {
+        match record.parse::<T>() {
+            Ok(item) => result.push(item),
+            Err(e) => {
+                let e = e.found_if_unknown(record).locate(input);
+                if is_strict() {
+                    return Err(e);
+                }
+                warn!("skipping record, {}", e);
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Parses every non-empty line of `input`.
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    parse_records(input, input.lines())
+}
+
+/// Parses every block of lines separated by an empty line.
+pub fn parse_blocks<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    parse_records(input, input.split("\n\n"))
+}
+
+/// A single day of the puzzle: the input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Checks of what the parts rely on beyond the puzzle statement, see [`assume`].
+    fn assumptions(_input: &Self::Input) -> Vec<Assumption> {
+        Vec::new()
+    }
+}
+
+/// Time spent in each phase of a solution.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+pub struct Solved {
+    pub answers: [Answer; 2],
+    pub timings: Timings,
+}
+
+pub type Solver = fn(&str) -> Result<Solved, ParseError>;
+
+pub type Parser = fn(&str) -> Result<(), ParseError>;
+
+pub fn solve<S: Solution>(input: &str) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|e| e.locate(input))?;
+    let [runs1, runs2] = assume::check(&S::assumptions(&parsed));
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = if runs1 {
+        S::part1(&parsed)
+    } else {
+        Answer::Empty
+    };
+    let part1_time = start.elapsed();
+
+    let start = Instant::now();
+    let part2 = if runs2 {
+        S::part2(&parsed)
+    } else {
+        Answer::Empty
+    };
+    let part2_time = start.elapsed();
+
+    Ok(Solved {
+        answers: [part1, part2],
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    })
+}
+
+/// Only parses the input, e.g. to check that a parser copes with any text.
+pub fn parse_only<S: Solution>(input: &str) -> Result<(), ParseError> {
+    S::parse(input).map(|_| ())
+}
+
+/// Parses the input and checks the assumptions of the solution on it, without
+/// solving, e.g. to check that the checks cope with any input that parses.
+pub fn assumptions_only<S: Solution>(input: &str) -> Result<(), ParseError> {
+    S::assumptions(&S::parse(input)?);
+    Ok(())
+}
+
+/// Parses a puzzle example, panicking with the located error on failure.
+pub fn parse_example<S: Solution>(input: &str) -> S::Input {
+    S::parse(input).unwrap_or_else(|e| panic!("{}", e.locate(input)))
+}
+
+/// Asserts both answers of a puzzle example.
+pub fn check_example<S: Solution>(input: &str, part1: impl Into<Answer>, part2: impl Into<Answer>) {
+    let input = parse_example::<S>(input);
+    assert_eq!(S::part1(&input), part1.into(), "part 1");
+    assert_eq!(S::part2(&input), part2.into(), "part 2");
+}
+
+/// Asserts the part 1 answer of an example that has no part 2 counterpart.
+pub fn check_part1<S: Solution>(input: &str, expected: impl Into<Answer>) {
+    assert_eq!(S::part1(&parse_example::<S>(input)), expected.into());
+}
+
+/// Asserts the part 2 answer of an example that has no part 1 counterpart.
+pub fn check_part2<S: Solution>(input: &str, expected: impl Into<Answer>) {
+    assert_eq!(S::part2(&parse_example::<S>(input)), expected.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    struct Number(i32);
+
+    impl FromStr for Number {
+        type Err = ParseError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (_, value) = s
+                .split_once('=')
+                .ok_or_else(|| ParseError::expected("name=value", s))?;
+            Ok(Number(parse_field(value, "number")?))
+        }
+    }
+
+    #[test]
+    fn test_locate() {
+        let input = "a=1\nb=2\nc=x3\n";
+        let e = parse_field::<i32>(&input[10..12], "number")
+            .unwrap_err()
+            .locate(input);
+        assert_eq!((e.line, e.column), (Some(3), Some(3)));
+        assert_eq!(
+            e.to_string(),
+            "parse error at line 3, column 3: expected number, found \"x3\""
+        );
+    }
+
+    #[test]
+    fn test_strict_mode() {
+        let input = "a=1\nb\n\nc=3\n";
+        let numbers: Vec<Number> = parse_lines(input).unwrap();
+        assert_eq!(numbers.iter().map(|n| n.0).collect::<Vec<_>>(), [1, 3]);
+
+        set_strict(true);
+        let e = parse_lines::<Number>(input).err().unwrap();
+        set_strict(false);
+        assert_eq!((e.line, e.column), (Some(2), Some(1)));
+        assert_eq!(e.expected.as_deref(), Some("name=value"));
+        assert_eq!(e.snippet.as_deref(), Some("b"));
+    }
+}
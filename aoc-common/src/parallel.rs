@@ -0,0 +1,116 @@
+//! Independent work items of a solver spread over a pool of threads.
+//!
+//! The runner sets the number of threads; with one, the default, the items are
+//! processed in order on the calling thread. The results come back in the
+//! order of the items either way, so the answers do not depend on the pool.
+//! Every worker takes the items of its own share from the front and, once it is
+//! done with them, steals from the back of the other shares.
+
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Stack of a worker, as large as the one of the main thread for the
+/// recursive solvers.
+const STACK_SIZE: usize = 8 << 20;
+
+static THREADS: AtomicUsize = AtomicUsize::new(1);
+
+thread_local! {
+    /// Nested calls from a worker run on the worker itself.
+    static IN_POOL: Cell<bool> = const { Cell::new(false) };
+}
+
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads.max(1), Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    THREADS.load(Ordering::Relaxed)
+}
+
+type Share<T> = Mutex<VecDeque<(usize, T)>>;
+
+/// Applies `f` to every item and returns the results in the order of the items.
+pub fn map<T, R, F>(items: impl IntoIterator<Item = T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let items: Vec<T> = items.into_iter().collect();
+    let threads = threads().min(items.len());
+    if threads <= 1 || IN_POOL.get() {
+        return items.into_iter().map(f).collect();
+    }
+
+    let len = items.len();
+    let shares: Vec<Share<T>> = (0..threads).map(|_| Mutex::default()).collect();
+    for (i, item) in items.into_iter().enumerate() {
+        shares[i * threads / len]
+            .lock()
+            .unwrap()
+            .push_back((i, item));
+    }
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|me| {
+                let (shares, f) = (&shares, &f);
+                thread::Builder::new()
+                    .stack_size(STACK_SIZE)
+                    .spawn_scoped(scope, move || {
+                        IN_POOL.set(true);
+                        let mut done = vec![];
+                        while let Some((i, item)) = next(shares, me) {
+                            done.push((i, f(item)));
+                        }
+                        done
+                    })
+                    .expect("cannot spawn a worker thread")
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+            .collect()
+    });
+    results.sort_unstable_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn next<T>(shares: &[Share<T>], me: usize) -> Option<(usize, T)> {
+    if let Some(item) = shares[me].lock().unwrap().pop_front() {
+        return Some(item);
+    }
+    (1..shares.len()).find_map(|k| shares[(me + k) % shares.len()].lock().unwrap().pop_back())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parallel::*;
+
+    #[test]
+    fn test_map() {
+        let sequential = map(0..1000u64, |i| i * i);
+        set_threads(4);
+        // uneven items, so that the workers steal
+        let parallel = map(0..1000u64, |i| {
+            if i < 10 {
+                thread::sleep(std::time::Duration::from_millis(5));
+            }
+            i * i
+        });
+        let nested = map(0..10u64, |i| map(0..i, |j| j).len());
+        set_threads(1);
+
+        assert_eq!(parallel, sequential);
+        assert_eq!(nested, (0..10).collect::<Vec<_>>());
+        assert!(map(Vec::<u64>::new(), |i| i).is_empty());
+    }
+}
@@ -0,0 +1,118 @@
+//! scanf-style templates for lines and blocks of puzzle input.
+//!
+//! A template is the text of the input with `{}` in place of every field,
+//! e.g. `"Sensor at x={}, y={}"`. A field ends where the text following it
+//! in the template first appears, the last one may also end with the input.
+//! Templates are plain constants, so there is nothing to compile per line.
+
+use crate::{parse_field, ParseError};
+use std::str::FromStr;
+
+const FIELD: &str = "{}";
+
+#[derive(Debug, Clone, Copy)]
+pub struct Template {
+    pattern: &'static str,
+}
+
+impl Template {
+    pub const fn new(pattern: &'static str) -> Self {
+        Template { pattern }
+    }
+
+    /// Splits `s` into the fields of the template. Errors point at the first
+    /// part of `s` that does not match.
+    pub fn scan<'a>(&self, s: &'a str) -> Result<Fields<'a>, ParseError> {
+        let mut pieces = self.pattern.split(FIELD);
+        let first = pieces.next().unwrap_or_default();
+        let mut rest = s
+            .strip_prefix(first)
+            .ok_or_else(|| ParseError::expected(format!("{:?}", first), s))?;
+        let mut fields = Vec::new();
+        for literal in pieces {
+            let end = if literal.is_empty() {
+                rest.len()
+            } else {
+                rest.find(literal)
+                    .ok_or_else(|| ParseError::expected(format!("{:?}", literal), rest))?
+            };
+            fields.push(&rest[..end]);
+            rest = &rest[end + literal.len()..];
+        }
+        if !rest.is_empty() {
+            return Err(ParseError::expected("end of the record", rest));
+        }
+        Ok(Fields { fields, next: 0 })
+    }
+}
+
+/// Fields of a scanned text, taken in the order of the template.
+#[derive(Debug)]
+pub struct Fields<'a> {
+    fields: Vec<&'a str>,
+    next: usize,
+}
+
+impl<'a> Fields<'a> {
+    /// The next field as it is.
+    ///
+    /// Panics if the template has no more fields, which is a bug of the
+    /// caller rather than of the input.
+    pub fn text(&mut self) -> &'a str {
+        let field = self.fields[self.next];
+        self.next += 1;
+        field
+    }
+
+    /// The next field parsed with `FromStr`, see [`parse_field`].
+    pub fn parse<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        parse_field(self.text(), expected)
+    }
+
+    /// Passes over fields that are not needed.
+    pub fn skip(&mut self, count: usize) -> &mut Self {
+        self.next += count;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scan::*;
+
+    const SENSOR: Template = Template::new("Sensor at x={}, y={}: beacon {}");
+
+    #[test]
+    fn test_scan() {
+        let mut fields = SENSOR.scan("Sensor at x=-2, y=15: beacon a, b").unwrap();
+        assert_eq!(fields.parse::<i32>("x"), Ok(-2));
+        assert_eq!(fields.parse::<i32>("y"), Ok(15));
+        assert_eq!(fields.text(), "a, b");
+
+        let mut fields = Template::new("{} -> {}!").scan("a -> b!").unwrap();
+        assert_eq!(fields.skip(1).text(), "b");
+        assert!(Template::new("{}").scan("").is_ok());
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let input = "Sensor at x=1, y=2: beacon\nSensor at x=1; y=2: beacon a";
+        let (first, second) = input.split_once('\n').unwrap();
+
+        let e = SENSOR.scan(first).unwrap_err().locate(input);
+        assert_eq!(e.expected.as_deref(), Some("\": beacon \""));
+        assert_eq!((e.line, e.column), (Some(1), Some(18)));
+
+        let e = SENSOR.scan(second).unwrap_err().locate(input);
+        assert_eq!(e.expected.as_deref(), Some("\", y=\""));
+        assert_eq!((e.line, e.column), (Some(2), Some(13)));
+
+        let mut fields = SENSOR.scan("Sensor at x=one, y=2: beacon a").unwrap();
+        let e = fields.parse::<i32>("x").unwrap_err();
+        assert_eq!(e.snippet.as_deref(), Some("one"));
+
+        let e = Template::new("a{}b").scan("a1b2").unwrap_err();
+        assert_eq!(e.snippet.as_deref(), Some("2"));
+        assert!(Template::new("Valve").scan("Vault").is_err());
+    }
+}
@@ -0,0 +1,152 @@
+//! Golden files of rendered text.
+//!
+//! [`assert_snapshot!`](crate::assert_snapshot) compares the text with
+//! `snapshots/<name>.txt` of the calling crate and fails with a line diff
+//! when they differ. Running the tests with `UPDATE_SNAPSHOTS=1` writes the
+//! new text into the golden files instead, which then go into the commit.
+
+use std::path::Path;
+
+/// Unchanged lines shown around every change of a diff.
+const CONTEXT: usize = 2;
+
+/// Checks `text` against `snapshots/<name>.txt` of the calling crate.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $text:expr) => {
+        $crate::snapshot::check(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+            $name,
+            &$text,
+        )
+    };
+}
+
+fn updating() -> bool {
+    std::env::var("UPDATE_SNAPSHOTS").is_ok_and(|v| !v.is_empty() && v != "0")
+}
+
+#[track_caller]
+pub fn check(dir: &Path, name: &str, text: &str) {
+    compare(dir, name, text, updating());
+}
+
+#[track_caller]
+fn compare(dir: &Path, name: &str, text: &str, update: bool) {
+    let path = dir.join("snapshots").join(format!("{}.txt", name));
+    let text = format!("{}\n", text.trim_end_matches('\n'));
+    if update {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, text).unwrap();
+        return;
+    }
+    let golden = match std::fs::read_to_string(&path) {
+        Ok(golden) => golden,
+        Err(e) => panic!(
+            "cannot read snapshot {}: {}; run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display(),
+            e
+        ),
+    };
+    if golden != text {
+        panic!(
+            "snapshot {} does not match (-golden +rendered), \
+             run with UPDATE_SNAPSHOTS=1 if the change is intended:\n{}",
+            path.display(),
+            diff(&golden, &text)
+        );
+    }
+}
+
+/// Shows the control characters, e.g. the colour codes, in a diff line.
+fn visible(line: &str) -> String {
+    line.chars()
+        .map(|c| match c {
+            '\x1b' => String::from("\\e"),
+            c if c.is_control() => c.escape_default().to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Line diff of the longest common subsequence, with a few lines of context.
+pub fn diff(old: &str, new: &str) -> String {
+    let (old, new): (Vec<&str>, Vec<&str>) = (old.lines().collect(), new.lines().collect());
+    // common[i][j] is the longest common subsequence of old[i..] and new[j..]
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = match old[i] == new[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push((' ', old[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(('-', old[i]));
+            i += 1;
+        } else {
+            lines.push(('+', new[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = (0..lines.len()).filter(|&k| lines[k].0 != ' ').collect();
+    if changed.is_empty() {
+        return String::new();
+    }
+    let shown = |k: usize| changed.iter().any(|&c| k.abs_diff(c) <= CONTEXT);
+    let mut result = String::new();
+    for (k, (mark, line)) in lines.iter().enumerate() {
+        if shown(k) {
+            result.push_str(&format!("{}{}\n", mark, visible(line)));
+        } else if k == 0 || shown(k - 1) {
+            result.push_str("...\n");
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::snapshot::*;
+
+    #[test]
+    fn test_diff() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl";
+        let new = "a\nb\nc\nD\ne\nf\ng\nh\ni\nj\nk\nl\nm";
+        assert_eq!(
+            diff(old, new),
+            "...\n b\n c\n-d\n+D\n e\n f\n...\n k\n l\n+m\n"
+        );
+        assert_eq!(diff("x\n", "x\n"), "");
+        assert_eq!(diff("", "\x1b[31mx"), "+\\e[31mx\n");
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = std::env::temp_dir().join(format!("aoc-snapshot-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("snapshots")).unwrap();
+        std::fs::write(dir.join("snapshots/screen.txt"), "#.\n.#\n").unwrap();
+        compare(&dir, "screen", "#.\n.#", false);
+        let mismatch = std::panic::catch_unwind(|| compare(&dir, "screen", "#.\n##", false));
+        let missing = std::panic::catch_unwind(|| compare(&dir, "other", "#.", false));
+        compare(&dir, "other", "#.", true);
+        compare(&dir, "other", "#.\n", false);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let message = mismatch.unwrap_err();
+        assert!(message
+            .downcast_ref::<String>()
+            .unwrap()
+            .ends_with(" #.\n-.#\n+##\n"));
+        assert!(missing.is_err());
+    }
+}
@@ -0,0 +1,353 @@
+//! Points and directions on unbounded planes and in space.
+//!
+//! `y` grows downwards like the rows of a [`Grid`](crate::Grid), so
+//! [`Dir4::Up`] decreases it and turning right goes clockwise on the screen.
+
+use crate::{Offset, Pos};
+use aoc_common::{parse_field, ParseError};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Both coordinates clamped to -1..=1, the step towards `self` from the origin.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Dir4::ALL.into_iter().map(move |d| self + d)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2> {
+        Dir8::ALL.into_iter().map(move |d| self + d)
+    }
+
+    /// The grid position of this point, if it has no negative coordinate.
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+}
+
+impl From<Pos> for Point2 {
+    fn from((r, c): Pos) -> Self {
+        Point2::new(c as i64, r as i64)
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        let d = self - other;
+        d.x.abs().max(d.y.abs()).max(d.z.abs())
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours6(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |d| self + d)
+    }
+}
+
+/// Comma-separated coordinates, e.g. `"2,-3"` for two of them.
+fn parse_coords<const N: usize>(s: &str, expected: &str) -> Result<[i64; N], ParseError> {
+    let s = s.trim();
+    let mut coords = [0; N];
+    let mut items = s.split(',');
+    for coord in coords.iter_mut() {
+        let item = items
+            .next()
+            .ok_or_else(|| ParseError::expected(expected, s))?;
+        *coord = parse_field(item.trim(), "coordinate")?;
+    }
+    match items.next() {
+        Some(_) => Err(ParseError::expected(expected, s)),
+        None => Ok(coords),
+    }
+}
+
+impl FromStr for Point2 {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_coords(s, "x,y")?;
+        Ok(Point2::new(x, y))
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_coords(s, "x,y,z")?;
+        Ok(Point3::new(x, y, z))
+    }
+}
+
+impl Dir4 {
+    /// Clockwise from up.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir4::Up => Point2::new(0, -1),
+            Dir4::Right => Point2::new(1, 0),
+            Dir4::Down => Point2::new(0, 1),
+            Dir4::Left => Point2::new(-1, 0),
+        }
+    }
+
+    /// The step in this direction on a [`Grid`](crate::Grid).
+    pub fn offset(self) -> Offset {
+        let d = self.delta();
+        (d.y as isize, d.x as isize)
+    }
+}
+
+impl Dir8 {
+    /// Clockwise from north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    /// Turns by 45 degrees.
+    pub fn turn_right(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45 degrees.
+    pub fn turn_left(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir8::N => Point2::new(0, -1),
+            Dir8::NE => Point2::new(1, -1),
+            Dir8::E => Point2::new(1, 0),
+            Dir8::SE => Point2::new(1, 1),
+            Dir8::S => Point2::new(0, 1),
+            Dir8::SW => Point2::new(-1, 1),
+            Dir8::W => Point2::new(-1, 0),
+            Dir8::NW => Point2::new(-1, -1),
+        }
+    }
+
+    pub fn offset(self) -> Offset {
+        let d = self.delta();
+        (d.y as isize, d.x as isize)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+macro_rules! vector_ops {
+    ($t:ident, $($c:ident),+) => {
+        impl Add for $t {
+            type Output = $t;
+            fn add(self, other: $t) -> $t {
+                $t { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl Sub for $t {
+            type Output = $t;
+            fn sub(self, other: $t) -> $t {
+                $t { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl Mul<i64> for $t {
+            type Output = $t;
+            fn mul(self, k: i64) -> $t {
+                $t { $($c: self.$c * k),+ }
+            }
+        }
+
+        impl Neg for $t {
+            type Output = $t;
+            fn neg(self) -> $t {
+                $t { $($c: -self.$c),+ }
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, other: $t) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, other: $t) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+vector_ops!(Point2, x, y);
+vector_ops!(Point3, x, y, z);
+
+impl Add<Dir4> for Point2 {
+    type Output = Point2;
+    fn add(self, dir: Dir4) -> Point2 {
+        self + dir.delta()
+    }
+}
+
+impl Add<Dir8> for Point2 {
+    type Output = Point2;
+    fn add(self, dir: Dir8) -> Point2 {
+        self + dir.delta()
+    }
+}
+
+impl AddAssign<Dir4> for Point2 {
+    fn add_assign(&mut self, dir: Dir4) {
+        *self = *self + dir;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::*;
+
+    #[test]
+    fn test_points() {
+        let (a, b) = (Point2::new(1, 2), Point2::new(-3, 5));
+        assert_eq!(a + b, Point2::new(-2, 7));
+        assert_eq!(a - b, Point2::new(4, -3));
+        assert_eq!(-a * 2, Point2::new(-2, -4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        assert_eq!(a + Dir4::Up, Point2::new(1, 1));
+        assert_eq!(a.neighbours4().count(), 4);
+        assert!(a.neighbours8().all(|n| n.chebyshev(a) == 1));
+        assert_eq!(Point2::from((2, 1)), a);
+        assert_eq!(a.to_pos(), Some((2, 1)));
+        assert_eq!(b.to_pos(), None);
+
+        let p = Point3::new(1, 2, 3);
+        assert_eq!(p.manhattan(Point3::ORIGIN), 6);
+        assert_eq!(p.chebyshev(Point3::new(0, 0, 0)), 3);
+        assert!(p.neighbours6().all(|n| n.manhattan(p) == 1));
+        assert_eq!(p.neighbours6().count(), 6);
+
+        assert_eq!("-1,2".parse(), Ok(Point2::new(-1, 2)));
+        assert_eq!(" 1, 2,3".parse(), Ok(p));
+        assert!("1,2".parse::<Point3>().is_err());
+        assert!("1,2,3".parse::<Point2>().is_err());
+        assert!("1,b".parse::<Point2>().is_err());
+    }
+
+    #[test]
+    fn test_directions() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.delta() + dir.opposite().delta(), Point2::ORIGIN);
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+        }
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Down.offset(), crate::DOWN);
+        assert_eq!(Dir4::Left.offset(), crate::LEFT);
+
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.delta() + dir.opposite().delta(), Point2::ORIGIN);
+        }
+        assert_eq!(Dir8::N.turn_right(), Dir8::NE);
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::NE.offset(), (-1, 1));
+    }
+}
@@ -0,0 +1,320 @@
+use aoc_common::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+pub mod geometry;
+
+pub use geometry::{Dir4, Dir8, Point2, Point3};
+
+/// (row, column)
+pub type Pos = (usize, usize);
+
+/// (row delta, column delta)
+pub type Offset = (isize, isize);
+
+pub const UP: Offset = (-1, 0);
+pub const DOWN: Offset = (1, 0);
+pub const LEFT: Offset = (0, -1);
+pub const RIGHT: Offset = (0, 1);
+
+pub const DIRS4: [Offset; 4] = [UP, RIGHT, DOWN, LEFT];
+pub const DIRS8: [Offset; 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// Grid cell with a single-character representation in the puzzle input.
+pub trait Tile: Sized {
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl Tile for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Dense rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses non-empty lines of `s`, mapping every character with `f`.
+    pub fn parse_with(s: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in s.lines().filter(|line| !line.is_empty()) {
+            let len = line.chars().count();
+            let width = *width.get_or_insert(len);
+            if len != width {
+                return Err(ParseError::expected(
+                    format!("row of {} cells", width),
+                    line,
+                ));
+            }
+            for (i, c) in line.char_indices() {
+                let cell = f(c)
+                    .ok_or_else(|| ParseError::expected("grid cell", &line[i..i + c.len_utf8()]))?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+        let width = width.ok_or_else(|| ParseError::new("empty grid"))?;
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (r, c): Pos) -> bool {
+        r < self.height && c < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// Position shifted by `offset`, if it stays inside the grid.
+    pub fn offset(&self, (r, c): Pos, (dr, dc): Offset) -> Option<Pos> {
+        let pos = (r.checked_add_signed(dr)?, c.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    pub fn get_offset(&self, pos: Pos, offset: Offset) -> Option<&T> {
+        self.offset(pos, offset).and_then(|pos| self.get(pos))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |r| (0..width).map(move |c| (r, c)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS4.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS8.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.width..(r + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, r: usize) -> &mut [T] {
+        &mut self.cells[r * self.width..(r + 1) * self.width]
+    }
+
+    /// All rows, also the empty ones of a grid without columns.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|r| self.row(r))
+    }
+
+    /// Cells of column `c` from the top, none past the last column.
+    pub fn column(&self, c: usize) -> impl Iterator<Item = &T> {
+        let height = if c < self.width { self.height } else { 0 };
+        // a grid without columns has no cells to step over
+        self.cells
+            .iter()
+            .skip(c)
+            .step_by(self.width.max(1))
+            .take(height)
+    }
+
+    /// Positions walked from `pos` (excluded) in steps of `offset` up to the edge.
+    pub fn ray(&self, pos: Pos, offset: Offset) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.offset(pos, offset), move |&p| self.offset(p, offset))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|c| self.column(c).cloned())
+                .collect(),
+        }
+    }
+
+    /// Rotates by 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|c| (0..self.height).rev().map(move |r| (r, c)))
+                .map(|pos| self[pos].clone())
+                .collect(),
+        }
+    }
+
+    /// Rotates by 90 degrees counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|c| (0..self.height).map(move |r| (r, c)))
+                .map(|pos| self[pos].clone())
+                .collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos).expect("position outside of the grid")
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos).expect("position outside of the grid")
+    }
+}
+
+impl<T: Tile> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, T::from_char)
+    }
+}
+
+/// Rows separated by newlines, without a trailing one.
+impl<T: Tile> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (r, row) in self.rows().enumerate() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_round_trip() {
+        let input = "#..\n.#.\n";
+        let grid: Grid<char> = input.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(format!("{}\n", grid), input);
+    }
+
+    #[test]
+    fn test_no_columns() {
+        let grid = Grid::new(0, 3, '.');
+        assert_eq!(grid.rows().count(), 3);
+        assert!(grid.rows().all(|row| row.is_empty()));
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.to_string(), "\n\n");
+        let transposed = grid.transpose();
+        assert_eq!((transposed.width(), transposed.height()), (3, 0));
+        assert_eq!(transposed.rows().count(), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = Grid::<char>::from_str("ab\nabc\n").unwrap_err();
+        assert_eq!(e.snippet.as_deref(), Some("abc"));
+
+        let e = Grid::parse_with("12\n3x\n", |c| c.to_digit(10))
+            .unwrap_err()
+            .locate("12\n3x\n");
+        assert_eq!((e.line, e.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn test_offsets() {
+        let grid: Grid<char> = "abc\ndef\n".parse().unwrap();
+        assert_eq!(grid.offset((0, 0), UP), None);
+        assert_eq!(grid.offset((0, 0), RIGHT), Some((0, 1)));
+        assert_eq!(grid.get_offset((1, 2), (-1, -2)), Some(&'a'));
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+        assert_eq!(
+            grid.ray((1, 0), RIGHT).collect::<Vec<_>>(),
+            [(1, 1), (1, 2)]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid: Grid<char> = "abc\ndef\n".parse().unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+}
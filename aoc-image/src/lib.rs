@@ -0,0 +1,324 @@
+//! Pictures of grid states, written as PNG or animated GIF.
+//!
+//! A picture has one pixel per grid cell and is scaled up when written. The
+//! solvers hand their pictures to [`capture`] and the runner decides where
+//! they end up, as with the terminal animation.
+
+use aoc_grid::{Grid, Pos};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const GREY: Rgb = [128, 128, 128];
+pub const RED: Rgb = [220, 40, 40];
+pub const GREEN: Rgb = [40, 180, 60];
+pub const BLUE: Rgb = [40, 80, 200];
+pub const YELLOW: Rgb = [240, 200, 40];
+
+/// Colour `t` of the way from `from` to `to`, `t` in 0..=1.
+pub fn gradient(from: Rgb, to: Rgb, t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    [
+        mix(from[0], to[0]),
+        mix(from[1], to[1]),
+        mix(from[2], to[2]),
+    ]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// One pixel per cell of `grid`.
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> Self {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.rows().flatten().map(color).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (r, c): Pos) -> Rgb {
+        self.pixels[r * self.width + c]
+    }
+
+    /// Colours the pixel at (row, column); pixels outside of the image are ignored.
+    pub fn set(&mut self, (r, c): Pos, color: Rgb) {
+        if r < self.height && c < self.width {
+            self.pixels[r * self.width + c] = color;
+        }
+    }
+
+    /// Every pixel becomes a `factor` × `factor` square.
+    pub fn scaled(&self, factor: usize) -> Image {
+        let factor = factor.max(1);
+        let mut scaled = Image::new(self.width * factor, self.height * factor, BLACK);
+        for r in 0..scaled.height {
+            for c in 0..scaled.width {
+                scaled.pixels[r * scaled.width + c] = self.get((r / factor, c / factor));
+            }
+        }
+        scaled
+    }
+
+    /// This image in the middle of a larger `width` × `height` one.
+    fn padded(&self, width: usize, height: usize, background: Rgb) -> Image {
+        let mut padded = Image::new(width, height, background);
+        let (top, left) = ((height - self.height) / 2, (width - self.width) / 2);
+        for r in 0..self.height {
+            for c in 0..self.width {
+                padded.set((top + r, left + c), self.get((r, c)));
+            }
+        }
+        padded
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+}
+
+fn create(path: &Path) -> Result<BufWriter<File>, String> {
+    File::create(path)
+        .map(BufWriter::new)
+        .map_err(|e| format!("cannot create {}: {}", path.display(), e))
+}
+
+fn open(path: &Path) -> Result<BufReader<File>, String> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|e| format!("cannot open {}: {}", path.display(), e))
+}
+
+pub fn write_png(path: &Path, image: &Image, scale: usize) -> Result<(), String> {
+    let image = image.scaled(scale);
+    let mut encoder = png::Encoder::new(create(path)?, image.width as u32, image.height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let error = |e: png::EncodingError| format!("cannot write {}: {}", path.display(), e);
+    let mut writer = encoder.write_header().map_err(error)?;
+    writer.write_image_data(&image.bytes()).map_err(error)?;
+    writer.finish().map_err(error)
+}
+
+pub fn read_png(path: &Path) -> Result<Image, String> {
+    let error = |e: png::DecodingError| format!("cannot read {}: {}", path.display(), e);
+    let mut decoder = png::Decoder::new(open(path)?);
+    decoder.set_transformations(png::Transformations::EXPAND);
+    let mut reader = decoder.read_info().map_err(error)?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).map_err(error)?;
+    let channels = info.color_type.samples();
+    if info.bit_depth != png::BitDepth::Eight || channels < 3 {
+        return Err(format!("{} is not an 8-bit colour image", path.display()));
+    }
+    let pixels = buffer[..info.buffer_size()]
+        .chunks(channels)
+        .map(|p| [p[0], p[1], p[2]])
+        .collect();
+    Ok(Image {
+        width: info.width as usize,
+        height: info.height as usize,
+        pixels,
+    })
+}
+
+/// Writes `frames` as a looping GIF, `delay_ms` apart.
+///
+/// Smaller frames are centred on the largest one. Every frame may use at
+/// most 256 colours.
+pub fn write_gif(path: &Path, frames: &[Image], scale: usize, delay_ms: u32) -> Result<(), String> {
+    let width = frames.iter().map(|f| f.width).max().unwrap_or(0);
+    let height = frames.iter().map(|f| f.height).max().unwrap_or(0);
+    let scale = scale.max(1);
+    let (screen_width, screen_height) = (width * scale, height * scale);
+    if screen_width > u16::MAX as usize || screen_height > u16::MAX as usize {
+        return Err(format!(
+            "{}x{} is too large for a GIF",
+            screen_width, screen_height
+        ));
+    }
+
+    let error = |e: gif::EncodingError| format!("cannot write {}: {}", path.display(), e);
+    let mut encoder = gif::Encoder::new(
+        create(path)?,
+        screen_width as u16,
+        screen_height as u16,
+        &[],
+    )
+    .map_err(error)?;
+    encoder.set_repeat(gif::Repeat::Infinite).map_err(error)?;
+    for (i, frame) in frames.iter().enumerate() {
+        let image = frame.padded(width, height, BLACK).scaled(scale);
+        let mut palette: HashMap<Rgb, u8> = HashMap::new();
+        let mut indices = Vec::with_capacity(image.pixels.len());
+        for pixel in &image.pixels {
+            let index = match palette.get(pixel) {
+                Some(&index) => index,
+                None if palette.len() == 256 => {
+                    return Err(format!("frame {} has more than 256 colours", i + 1))
+                }
+                None => {
+                    let index = palette.len() as u8;
+                    palette.insert(*pixel, index);
+                    index
+                }
+            };
+            indices.push(index);
+        }
+        let mut colors = vec![BLACK; palette.len()];
+        for (color, index) in palette {
+            colors[index as usize] = color;
+        }
+        let frame = gif::Frame {
+            width: screen_width as u16,
+            height: screen_height as u16,
+            delay: (delay_ms / 10) as u16,
+            palette: Some(colors.concat()),
+            buffer: Cow::Owned(indices),
+            ..Default::default()
+        };
+        encoder.write_frame(&frame).map_err(error)?;
+    }
+    Ok(())
+}
+
+pub fn read_gif(path: &Path) -> Result<Vec<Image>, String> {
+    let error = |e: gif::DecodingError| format!("cannot read {}: {}", path.display(), e);
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options.read_info(open(path)?).map_err(error)?;
+    let mut frames = vec![];
+    while let Some(frame) = decoder.read_next_frame().map_err(error)? {
+        frames.push(Image {
+            width: frame.width as usize,
+            height: frame.height as usize,
+            pixels: frame.buffer.chunks(4).map(|p| [p[0], p[1], p[2]]).collect(),
+        });
+    }
+    Ok(frames)
+}
+
+/// Receiver of the pictures, e.g. a PNG or GIF writer.
+pub trait Sink: Send {
+    fn picture(&mut self, image: Image);
+
+    /// Writes out what was collected, once the solvers are done.
+    fn finish(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SINK: Mutex<Option<Box<dyn Sink>>> = Mutex::new(None);
+
+pub fn set_sink(sink: Box<dyn Sink>) {
+    *SINK.lock().unwrap() = Some(sink);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Removes the sink, so that it can write out what it collected.
+pub fn take_sink() -> Option<Box<dyn Sink>> {
+    ENABLED.store(false, Ordering::Relaxed);
+    SINK.lock().unwrap().take()
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Sends the picture drawn by `draw` to the sink, if there is one.
+pub fn capture(draw: impl FnOnce() -> Image) {
+    if !enabled() {
+        return;
+    }
+    let image = draw();
+    if let Some(sink) = SINK.lock().unwrap().as_mut() {
+        sink.picture(image);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("aoc-image-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_png_round_trip() {
+        let grid: Grid<char> = "#.\n.#\n.#".parse().unwrap();
+        let mut image = Image::from_grid(&grid, |&c| if c == '#' { WHITE } else { BLACK });
+        image.set((2, 0), RED);
+        image.set((5, 5), RED);
+
+        let path = temp_path("round-trip.png");
+        write_png(&path, &image, 3).unwrap();
+        let read = read_png(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!((read.width(), read.height()), (6, 9));
+        assert_eq!(read.get((0, 0)), WHITE);
+        assert_eq!(read.get((2, 2)), WHITE);
+        assert_eq!(read.get((0, 3)), BLACK);
+        assert_eq!(read.get((8, 0)), RED);
+        assert_eq!(read, image.scaled(3));
+    }
+
+    #[test]
+    fn test_gif_frames() {
+        let small = Image::new(1, 1, BLUE);
+        let mut large = Image::new(3, 3, BLACK);
+        large.set((2, 2), gradient(BLACK, WHITE, 0.5));
+
+        let path = temp_path("frames.gif");
+        write_gif(&path, &[small, large.clone()], 2, 100).unwrap();
+        let frames = read_gif(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!((frames[0].width(), frames[0].height()), (6, 6));
+        assert_eq!(frames[0].get((0, 0)), BLACK);
+        assert_eq!(frames[0].get((2, 2)), BLUE);
+        assert_eq!(frames[0].get((3, 3)), BLUE);
+        assert_eq!(frames[1], large.scaled(2));
+        assert_eq!(frames[1].get((5, 5)), [128, 128, 128]);
+
+        let mut colorful = Image::new(300, 1, BLACK);
+        for c in 0..300 {
+            colorful.set((0, c), [c as u8, (c / 256) as u8, 0]);
+        }
+        assert!(write_gif(&temp_path("colorful.gif"), &[colorful], 1, 100).is_err());
+        let _ = std::fs::remove_file(temp_path("colorful.gif"));
+    }
+}
@@ -0,0 +1,493 @@
+use std::fmt::Debug;
+use std::ops::{Add, Range, RangeInclusive, Sub};
+
+/// Integer type usable as an interval bound.
+pub trait Int: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(impl Int for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+        })*
+    };
+}
+
+impl_int!(i32, i64, isize, u32, u64, usize);
+
+/// `start..=end` as `start..end + 1`.
+pub fn half_open<T: Int>(r: &RangeInclusive<T>) -> Range<T> {
+    *r.start()..*r.end() + T::ONE
+}
+
+/// `start..end` as `start..=end - 1`, or `None` when the range is empty.
+pub fn inclusive<T: Int>(r: &Range<T>) -> Option<RangeInclusive<T>> {
+    (r.start < r.end).then(|| r.start..=r.end - T::ONE)
+}
+
+/// Set of integers kept as sorted, disjoint and non-adjacent half-open ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Int> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn inclusive_ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().filter_map(inclusive)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |len, r| len + (r.end - r.start))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|r| r.end - T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+        // ranges[lo..hi] overlap or touch the new one
+        let lo = self.ranges.partition_point(|r| r.end < range.start);
+        let hi = self.ranges.partition_point(|r| r.start <= range.end);
+        let (mut start, mut end) = (range.start, range.end);
+        if lo < hi {
+            start = start.min(self.ranges[lo].start);
+            end = end.max(self.ranges[hi - 1].end);
+        }
+        self.ranges.splice(lo..hi, [start..end]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.extend(other.ranges.iter().cloned());
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for a in self.ranges.iter() {
+            let mut start = a.start;
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            for b in other.ranges[j..].iter().take_while(|b| b.start < a.end) {
+                if b.start > start {
+                    ranges.push(start..b.start);
+                }
+                start = start.max(b.end);
+            }
+            if start < a.end {
+                ranges.push(start..a.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Everything in `T::MIN..T::MAX` that is not in the set.
+    pub fn complement(&self) -> Self {
+        IntervalSet::from(T::MIN..T::MAX).difference(self)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// Splits into the values below `at` and the values from `at` up.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let i = self.ranges.partition_point(|r| r.end <= at);
+        let mut below = self.ranges[..i].to_vec();
+        let mut above = self.ranges[i..].to_vec();
+        if let Some(r) = above.first_mut() {
+            if r.start < at {
+                below.push(r.start..at);
+                r.start = at;
+            }
+        }
+        (IntervalSet { ranges: below }, IntervalSet { ranges: above })
+    }
+}
+
+impl<T: Int> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Int> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        IntervalSet::from(half_open(&range))
+    }
+}
+
+impl<T: Int> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        let mut ranges: Vec<_> = self.ranges.drain(..).collect();
+        ranges.extend(iter.into_iter().filter(|r| r.start < r.end));
+        ranges.sort_by_key(|r| r.start);
+        for r in ranges {
+            match self.ranges.last_mut() {
+                Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+                _ => self.ranges.push(r),
+            }
+        }
+    }
+}
+
+impl<T: Int> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Int> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        iter.into_iter().map(|r| half_open(&r)).collect()
+    }
+}
+
+/// Piecewise-linear map with unit slope: every segment shifts its source
+/// range onto a destination start. Values outside all segments map to
+/// themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PiecewiseMap<T> {
+    /// (source range, destination start), sorted by source
+    segments: Vec<(Range<T>, T)>,
+}
+
+impl<T: Int> PiecewiseMap<T> {
+    pub fn new() -> Self {
+        PiecewiseMap {
+            segments: Vec::new(),
+        }
+    }
+
+    pub fn segments(&self) -> &[(Range<T>, T)] {
+        &self.segments
+    }
+
+    /// Maps `source` onto `dest..`. Values already covered by an earlier
+    /// segment keep their mapping.
+    pub fn insert(&mut self, source: Range<T>, dest: T) {
+        let free = IntervalSet::from(source.clone()).difference(&self.domain());
+        for r in free.ranges {
+            let d = dest + (r.start - source.start);
+            self.segments.push((r, d));
+        }
+        self.segments.sort_by_key(|(r, _)| r.start);
+    }
+
+    /// Values covered by some segment.
+    pub fn domain(&self) -> IntervalSet<T> {
+        self.segments.iter().map(|(r, _)| r.clone()).collect()
+    }
+
+    pub fn map(&self, value: T) -> T {
+        let i = self.segments.partition_point(|(r, _)| r.end <= value);
+        match self.segments.get(i) {
+            Some((r, d)) if r.start <= value => *d + (value - r.start),
+            _ => value,
+        }
+    }
+
+    /// Image of every value in `set`.
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.ranges
+            .iter()
+            .flat_map(|r| self.pieces(r.clone()))
+            .map(|(r, d)| d..d + (r.end - r.start))
+            .collect()
+    }
+
+    /// Composition applying `self` first and `next` after it.
+    pub fn then(&self, next: &Self) -> Self {
+        let mut segments = Vec::new();
+        for (r, d) in self.segments.iter() {
+            let image = *d..*d + (r.end - r.start);
+            for (piece, pd) in next.pieces(image) {
+                let start = r.start + (piece.start - *d);
+                segments.push((start..start + (piece.end - piece.start), pd));
+            }
+        }
+        let own = self.domain();
+        for (r, d) in next.segments.iter() {
+            for piece in IntervalSet::from(r.clone()).difference(&own).ranges {
+                let pd = *d + (piece.start - r.start);
+                segments.push((piece, pd));
+            }
+        }
+        segments.sort_by_key(|(r, _)| r.start);
+        PiecewiseMap { segments }
+    }
+
+    /// Inverse map, if `self` is a bijection.
+    pub fn inverse(&self) -> Option<Self> {
+        let mut segments: Vec<_> = self
+            .segments
+            .iter()
+            .map(|(r, d)| (*d..*d + (r.end - r.start), r.start))
+            .collect();
+        // outside of the domain the map is the identity, so the images have
+        // to cover exactly the domain without overlapping
+        let image: IntervalSet<T> = segments.iter().map(|(r, _)| r.clone()).collect();
+        if image != self.domain() {
+            return None;
+        }
+        segments.sort_by_key(|(r, _)| r.start);
+        Some(PiecewiseMap { segments })
+    }
+
+    /// Splits `range` into pieces moved by a single shift, with the
+    /// destination start of each.
+    fn pieces(&self, range: Range<T>) -> Vec<(Range<T>, T)> {
+        let mut pieces = Vec::new();
+        let mut start = range.start;
+        let i = self.segments.partition_point(|(r, _)| r.end <= start);
+        for (r, d) in self.segments[i..].iter() {
+            if start >= range.end || r.start >= range.end {
+                break;
+            }
+            if r.start > start {
+                pieces.push((start..r.start, start));
+                start = r.start;
+            }
+            let end = r.end.min(range.end);
+            pieces.push((start..end, *d + (start - r.start)));
+            start = end;
+        }
+        if start < range.end {
+            pieces.push((start..range.end, start));
+        }
+        pieces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    const DOMAIN: Range<i32> = -80..80;
+
+    fn ranges() -> impl Strategy<Value = Vec<Range<i32>>> {
+        prop::collection::vec((-50..50i32, 0..20i32).prop_map(|(s, l)| s..s + l), 0..6)
+    }
+
+    fn segments() -> impl Strategy<Value = Vec<(Range<i32>, i32)>> {
+        prop::collection::vec(
+            (-50..50i32, 0..20i32, -50..50i32).prop_map(|(s, l, d)| (s..s + l, d)),
+            0..5,
+        )
+    }
+
+    fn model(ranges: &[Range<i32>]) -> BTreeSet<i32> {
+        ranges.iter().flat_map(|r| r.clone()).collect()
+    }
+
+    fn piecewise(segments: &[(Range<i32>, i32)]) -> PiecewiseMap<i32> {
+        let mut map = PiecewiseMap::new();
+        for (r, d) in segments {
+            map.insert(r.clone(), *d);
+        }
+        map
+    }
+
+    /// First matching segment wins.
+    fn map_model(segments: &[(Range<i32>, i32)], x: i32) -> i32 {
+        segments
+            .iter()
+            .find(|(r, _)| r.contains(&x))
+            .map_or(x, |(r, d)| d + (x - r.start))
+    }
+
+    fn assert_canonical(set: &IntervalSet<i32>) {
+        assert!(set.ranges().iter().all(|r| r.start < r.end));
+        assert!(set.ranges().windows(2).all(|w| w[0].end < w[1].start));
+    }
+
+    #[test]
+    fn test_set_ops() {
+        let a: IntervalSet<i32> = [1..5, 8..10, 4..6].into_iter().collect();
+        assert_eq!(a.ranges(), [1..6, 8..10]);
+        assert_eq!(a.len(), 7);
+        assert_eq!(a.inclusive_ranges().collect::<Vec<_>>(), [1..=5, 8..=9]);
+
+        let b = IntervalSet::from(5..=8);
+        assert_eq!(a.union(&b), IntervalSet::from(1..10));
+        assert_eq!(a.intersection(&b).ranges(), [5..6, 8..9]);
+        assert_eq!(a.difference(&b).ranges(), [1..5, 9..10]);
+        assert_eq!(a.complement().ranges(), [i32::MIN..1, 6..8, 10..i32::MAX]);
+
+        let (below, above) = a.split_at(4);
+        assert_eq!(below, IntervalSet::from(1..4));
+        assert_eq!(above.ranges(), [4..6, 8..10]);
+    }
+
+    #[test]
+    fn test_piecewise_map() {
+        let mut map = PiecewiseMap::new();
+        map.insert(98..100, 50);
+        map.insert(50..98, 52);
+        assert_eq!(map.map(79), 81);
+        assert_eq!(map.map(99), 51);
+        assert_eq!(map.map(10), 10);
+        assert_eq!(
+            map.map_set(&IntervalSet::from(90..110)).ranges(),
+            [50..52, 92..110]
+        );
+        let inverse = map.inverse().unwrap();
+        assert_eq!(inverse.map(51), 99);
+        assert_eq!(inverse.map(81), 79);
+
+        map.insert(0..10, 200);
+        assert_eq!(map.inverse(), None);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_set_ops(a in ranges(), b in ranges()) {
+            let (ma, mb) = (model(&a), model(&b));
+            let a: IntervalSet<i32> = a.into_iter().collect();
+            let b: IntervalSet<i32> = b.into_iter().collect();
+            let (union, intersection, difference) = (a.union(&b), a.intersection(&b), a.difference(&b));
+            let complement = a.complement();
+            for set in [&a, &union, &intersection, &difference, &complement] {
+                assert_canonical(set);
+            }
+            prop_assert_eq!(a.len() as usize, ma.len());
+            prop_assert_eq!(a.min(), ma.first().copied());
+            prop_assert_eq!(a.max(), ma.last().copied());
+            for x in DOMAIN {
+                let (in_a, in_b) = (ma.contains(&x), mb.contains(&x));
+                prop_assert_eq!(a.contains(x), in_a);
+                prop_assert_eq!(union.contains(x), in_a || in_b);
+                prop_assert_eq!(intersection.contains(x), in_a && in_b);
+                prop_assert_eq!(difference.contains(x), in_a && !in_b);
+                prop_assert_eq!(complement.contains(x), !in_a);
+            }
+            prop_assert_eq!(a.is_subset(&union), true);
+            prop_assert_eq!(difference.is_disjoint(&b), true);
+        }
+
+        #[test]
+        fn prop_insert_and_split(a in ranges(), at in DOMAIN) {
+            let mut set = IntervalSet::new();
+            for r in a.iter() {
+                set.insert(r.clone());
+            }
+            assert_canonical(&set);
+            prop_assert_eq!(&set, &a.iter().cloned().collect());
+
+            let (below, above) = set.split_at(at);
+            assert_canonical(&below);
+            assert_canonical(&above);
+            prop_assert_eq!(below.union(&above), set);
+            prop_assert!(below.max().is_none_or(|m| m < at));
+            prop_assert!(above.min().is_none_or(|m| m >= at));
+        }
+
+        #[test]
+        fn prop_inclusive_round_trip(start in -100..100i32, len in 0..10i32) {
+            let r = start..start + len;
+            match inclusive(&r) {
+                Some(i) => prop_assert_eq!(half_open(&i), r),
+                None => prop_assert_eq!(len, 0),
+            }
+        }
+
+        #[test]
+        fn prop_piecewise_map(first in segments(), second in segments(), a in ranges()) {
+            let (f, g) = (piecewise(&first), piecewise(&second));
+            let composed = f.then(&g);
+            let image = f.map_set(&a.iter().cloned().collect());
+            assert_canonical(&image);
+            let image_model: BTreeSet<i32> = model(&a).into_iter().map(|x| map_model(&first, x)).collect();
+            prop_assert_eq!(image.len() as usize, image_model.len());
+            for x in DOMAIN {
+                prop_assert_eq!(f.map(x), map_model(&first, x));
+                prop_assert_eq!(composed.map(x), g.map(f.map(x)));
+                prop_assert_eq!(image.contains(x), image_model.contains(&x));
+            }
+            if let Some(inverse) = f.inverse() {
+                for x in DOMAIN {
+                    prop_assert_eq!(inverse.map(f.map(x)), x);
+                }
+            }
+        }
+
+        #[test]
+        fn prop_inverse_of_swap(start in -50..50i32, len in 1..20i32, gap in 0..10i32) {
+            // swaps two blocks of the same length
+            let other = start + len + gap;
+            let mut map = PiecewiseMap::new();
+            map.insert(start..start + len, other);
+            map.insert(other..other + len, start);
+            let inverse = map.inverse().unwrap();
+            for x in DOMAIN {
+                prop_assert_eq!(inverse.map(map.map(x)), x);
+                prop_assert_eq!(map.then(&inverse).map(x), x);
+            }
+        }
+    }
+}
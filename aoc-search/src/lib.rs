@@ -0,0 +1,230 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Outcome of a search.
+///
+/// `dist` holds the best known cost of every reached node. When the search
+/// stopped at a goal the costs of the unfinished frontier may not be final;
+/// use a goal predicate that never matches to get the full distance map.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    pub dist: HashMap<N, C>,
+    pub goal: Option<N>,
+    prev: HashMap<N, N>,
+}
+
+impl<N: Hash + Eq + Clone, C: Copy> Search<N, C> {
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.as_ref().map(|goal| self.dist[goal])
+    }
+
+    /// Nodes from one of the starts to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.dist.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.prev.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth-first search over unit-cost edges.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut dist = HashMap::new();
+    let mut prev = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !dist.contains_key(&start) {
+            dist.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(u) = queue.pop_front() {
+        if is_goal(&u) {
+            return Search {
+                dist,
+                goal: Some(u),
+                prev,
+            };
+        }
+        let d = dist[&u] + 1;
+        for v in neighbours(&u) {
+            if !dist.contains_key(&v) {
+                dist.insert(v.clone(), d);
+                prev.insert(v.clone(), u.clone());
+                queue.push_back(v);
+            }
+        }
+    }
+
+    Search {
+        dist,
+        goal: None,
+        prev,
+    }
+}
+
+/// Dijkstra's shortest paths; `neighbours` yields `(node, edge cost)`.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search; `heuristic` must never overestimate the remaining cost.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut dist = HashMap::new();
+    let mut prev = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let cost = C::default();
+        dist.insert(start.clone(), cost);
+        queue.push(Queued {
+            priority: cost + heuristic(&start),
+            cost,
+            node: start,
+        });
+    }
+
+    while let Some(Queued { cost, node: u, .. }) = queue.pop() {
+        if cost > dist[&u] {
+            // outdated entry, the node was reached more cheaply since
+            continue;
+        }
+        if is_goal(&u) {
+            return Search {
+                dist,
+                goal: Some(u),
+                prev,
+            };
+        }
+        for (v, edge) in neighbours(&u) {
+            let alt = cost + edge;
+            if dist.get(&v).is_none_or(|&d| alt < d) {
+                dist.insert(v.clone(), alt);
+                prev.insert(v.clone(), u.clone());
+                queue.push(Queued {
+                    priority: alt + heuristic(&v),
+                    cost: alt,
+                    node: v,
+                });
+            }
+        }
+    }
+
+    Search {
+        dist,
+        goal: None,
+        prev,
+    }
+}
+
+/// Heap entry ordered by the lowest priority first.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    // 0 -1- 1 -1- 2
+    //  \         /
+    //   ----5----
+    fn edges(n: &u32) -> Vec<(u32, u32)> {
+        match n {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(0, 1), (2, 1)],
+            2 => vec![(1, 1), (0, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs([0], |n| edges(n).into_iter().map(|(v, _)| v), |&n| n == 2);
+        assert_eq!(search.goal_cost(), Some(1));
+        assert_eq!(search.path(), Some(vec![0, 2]));
+
+        let search = bfs([0, 2], |n| edges(n).into_iter().map(|(v, _)| v), |_| false);
+        assert_eq!(search.goal, None);
+        assert_eq!(search.dist, HashMap::from([(0, 0), (1, 1), (2, 0)]));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra([0], edges, |&n| n == 2);
+        assert_eq!(search.goal_cost(), Some(2));
+        assert_eq!(search.path(), Some(vec![0, 1, 2]));
+        assert_eq!(search.path_to(&3), None);
+
+        let search = dijkstra([0], edges, |&n| n == 3);
+        assert_eq!(search.goal, None);
+        assert_eq!(search.dist, HashMap::from([(0, 0), (1, 1), (2, 2)]));
+    }
+
+    #[test]
+    fn test_astar() {
+        let search = astar([0], edges, |&n| 2 - n.min(2), |&n| n == 2);
+        assert_eq!(search.goal_cost(), Some(2));
+        assert_eq!(search.path(), Some(vec![0, 1, 2]));
+    }
+}
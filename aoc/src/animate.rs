@@ -0,0 +1,323 @@
+use aoc_common::anim::{Color, Frame, Sink};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, terminal};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Viewport of a recording made without a terminal.
+const HEADLESS_VIEW: (usize, usize) = (22, 80);
+const HELP: &str = "space pause, n next frame, +/- speed, q stop animating";
+
+/// Moves the `origin` of a `view` over `size` cells as little as possible to
+/// keep `focus` a quarter of the view away from the edges.
+fn follow(origin: usize, view: usize, size: usize, focus: Option<usize>) -> usize {
+    let margin = view / 4;
+    let mut origin = origin;
+    if let Some(focus) = focus {
+        if focus < origin + margin {
+            origin = focus.saturating_sub(margin);
+        } else if focus + margin >= origin + view {
+            origin = focus + margin + 1 - view;
+        }
+    }
+    origin.min(size.saturating_sub(view))
+}
+
+fn ansi(color: Option<Color>) -> &'static str {
+    match color {
+        None => "\x1b[0m",
+        Some(Color::Red) => "\x1b[31m",
+        Some(Color::Green) => "\x1b[32m",
+        Some(Color::Yellow) => "\x1b[33m",
+        Some(Color::Blue) => "\x1b[34m",
+        Some(Color::Magenta) => "\x1b[35m",
+        Some(Color::Cyan) => "\x1b[36m",
+        Some(Color::Grey) => "\x1b[90m",
+    }
+}
+
+/// Draws the part of `frame` seen through the viewport, from the top-left
+/// corner of the screen, with the caption above and `status` below.
+fn render(frame: &Frame, origin: (usize, usize), view: (usize, usize), status: &str) -> String {
+    let mut out = String::from("\x1b[H");
+    out.extend(frame.caption.chars().take(view.1));
+    out.push_str("\x1b[K\r\n");
+    for row in frame.rows.iter().skip(origin.0).take(view.0) {
+        let mut color = None;
+        for cell in row.iter().skip(origin.1).take(view.1) {
+            if cell.color != color {
+                color = cell.color;
+                out.push_str(ansi(color));
+            }
+            out.push(cell.ch);
+        }
+        if color.is_some() {
+            out.push_str(ansi(None));
+        }
+        out.push_str("\x1b[K\r\n");
+    }
+    out.extend(status.chars().take(view.1));
+    out.push_str("\x1b[K\x1b[J");
+    out
+}
+
+/// Asciicast v2 recording with a fixed time between the frames.
+struct Cast {
+    file: BufWriter<File>,
+    time: f64,
+}
+
+impl Cast {
+    fn create(path: &Path, view: (usize, usize)) -> Result<Self, String> {
+        let file =
+            File::create(path).map_err(|e| format!("cannot create {}: {}", path.display(), e))?;
+        let mut cast = Cast {
+            file: BufWriter::new(file),
+            time: 0.0,
+        };
+        let header = serde_json::json!({
+            "version": 2,
+            "width": view.1,
+            "height": view.0 + 2,
+        });
+        writeln!(cast.file, "{}", header).map_err(|e| e.to_string())?;
+        Ok(cast)
+    }
+
+    fn push(&mut self, screen: &str, delay: Duration) {
+        let event = serde_json::json!([self.time, "o", screen]);
+        // a failing write only spoils the recording, not the solution
+        let _ = writeln!(self.file, "{}", event);
+        self.time += delay.as_secs_f64();
+    }
+}
+
+/// Plays the frames in the terminal and/or records them.
+pub struct Player {
+    interactive: bool,
+    delay: Duration,
+    last: Option<Instant>,
+    origin: (usize, usize),
+    paused: bool,
+    frames: usize,
+    cast: Option<Cast>,
+}
+
+impl Player {
+    /// Takes over the terminal if `interactive`; otherwise only records to `record`.
+    pub fn new(interactive: bool, fps: f64, record: Option<&Path>) -> Result<Self, String> {
+        let view = if interactive {
+            Self::terminal_view()
+        } else {
+            HEADLESS_VIEW
+        };
+        let cast = record.map(|path| Cast::create(path, view)).transpose()?;
+        if interactive {
+            terminal::enable_raw_mode().map_err(|e| e.to_string())?;
+            execute!(
+                std::io::stderr(),
+                terminal::EnterAlternateScreen,
+                cursor::Hide
+            )
+            .map_err(|e| e.to_string())?;
+            // leave the terminal usable if a solver panics mid-animation
+            let hook = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                let _ = execute!(
+                    std::io::stderr(),
+                    cursor::Show,
+                    terminal::LeaveAlternateScreen
+                );
+                let _ = terminal::disable_raw_mode();
+                hook(info);
+            }));
+        }
+        Ok(Player {
+            interactive,
+            delay: Duration::from_secs_f64(1.0 / fps),
+            last: None,
+            origin: (0, 0),
+            paused: false,
+            frames: 0,
+            cast,
+        })
+    }
+
+    fn terminal_view() -> (usize, usize) {
+        let (cols, rows) = terminal::size().unwrap_or((80, 24));
+        ((rows as usize).saturating_sub(2), cols as usize)
+    }
+
+    fn view(&self) -> (usize, usize) {
+        if self.interactive {
+            Self::terminal_view()
+        } else {
+            HEADLESS_VIEW
+        }
+    }
+
+    fn status(&self) -> String {
+        let state = if self.paused { "paused" } else { "playing" };
+        format!(
+            "frame {}  {:.0} fps  {}  ({})",
+            self.frames,
+            1.0 / self.delay.as_secs_f64(),
+            state,
+            HELP
+        )
+    }
+
+    /// Reacts to a key; returns false when the animation should stop.
+    fn key(&mut self, key: KeyEvent, step: &mut bool) -> bool {
+        if key.kind != KeyEventKind::Press {
+            return true;
+        }
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.restore();
+                std::process::exit(130);
+            }
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Char('n') | KeyCode::Right => *step = true,
+            KeyCode::Char('+') => self.delay = (self.delay / 2).max(Duration::from_micros(100)),
+            KeyCode::Char('-') => self.delay = (self.delay * 2).min(Duration::from_secs(10)),
+            _ => {}
+        }
+        true
+    }
+
+    /// Handles the keys pressed so far and waits while paused.
+    fn controls(&mut self) -> bool {
+        let mut step = false;
+        loop {
+            let timeout = if self.paused {
+                Duration::from_millis(100)
+            } else {
+                Duration::ZERO
+            };
+            while let Ok(true) = event::poll(timeout) {
+                if let Ok(Event::Key(key)) = event::read() {
+                    if !self.key(key, &mut step) {
+                        return false;
+                    }
+                }
+                if !self.paused || step {
+                    break;
+                }
+            }
+            if !self.paused || step {
+                return true;
+            }
+        }
+    }
+
+    fn restore(&mut self) {
+        if self.interactive {
+            let _ = execute!(
+                std::io::stderr(),
+                cursor::Show,
+                terminal::LeaveAlternateScreen
+            );
+            let _ = terminal::disable_raw_mode();
+            self.interactive = false;
+        }
+    }
+}
+
+impl Sink for Player {
+    fn frame(&mut self, frame: &Frame) -> bool {
+        self.frames += 1;
+        let view = self.view();
+        let (focus_row, focus_col) = frame.focus.unzip();
+        self.origin = (
+            follow(self.origin.0, view.0, frame.height(), focus_row),
+            follow(self.origin.1, view.1, frame.width(), focus_col),
+        );
+        let screen = render(frame, self.origin, view, &self.status());
+
+        if let Some(cast) = &mut self.cast {
+            cast.push(&screen, self.delay);
+        }
+        if !self.interactive {
+            return true;
+        }
+
+        if let Some(last) = self.last {
+            std::thread::sleep(self.delay.saturating_sub(last.elapsed()));
+        }
+        let mut stderr = std::io::stderr();
+        let _ = stderr.write_all(screen.as_bytes());
+        let _ = stderr.flush();
+        self.last = Some(Instant::now());
+        let more = self.controls();
+        if !more {
+            self.restore();
+        }
+        more
+    }
+}
+
+impl Drop for Player {
+    fn drop(&mut self) {
+        self.restore();
+        if let Some(cast) = &mut self.cast {
+            let _ = cast.file.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::animate::*;
+
+    #[test]
+    fn test_follow() {
+        // no focus keeps the view where it is, within the frame
+        assert_eq!(follow(5, 10, 100, None), 5);
+        assert_eq!(follow(95, 10, 100, None), 90);
+        assert_eq!(follow(0, 10, 5, None), 0);
+        // the focus is kept at least a quarter of the view from the edges
+        assert_eq!(follow(0, 20, 100, Some(10)), 0);
+        assert_eq!(follow(0, 20, 100, Some(15)), 1);
+        assert_eq!(follow(50, 20, 100, Some(52)), 47);
+        assert_eq!(follow(0, 20, 100, Some(99)), 80);
+    }
+
+    #[test]
+    fn test_render_and_record() {
+        let frame = Frame::from_text("minute 1", "abcd\nefgh\nijkl")
+            .color(1, 2, Color::Red)
+            .focus(2, 3);
+        let screen = render(&frame, (1, 1), (2, 2), "status");
+        assert_eq!(
+            screen,
+            "\x1b[Hmi\x1b[K\r\nf\x1b[31mg\x1b[0m\x1b[K\r\njk\x1b[K\r\nst\x1b[K\x1b[J"
+        );
+
+        let path = std::env::temp_dir().join(format!("aoc-anim-{}.cast", std::process::id()));
+        let mut player = Player::new(false, 10.0, Some(&path)).unwrap();
+        assert!(player.frame(&frame));
+        assert!(player.frame(&frame));
+        drop(player);
+        let cast = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let lines: Vec<serde_json::Value> = cast
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["version"], 2);
+        assert_eq!(
+            (lines[0]["width"].as_u64(), lines[0]["height"].as_u64()),
+            (Some(80), Some(24))
+        );
+        assert_eq!(lines[1][0], 0.0);
+        assert_eq!(lines[2][0], 0.1);
+        assert_eq!(lines[2][1], "o");
+        assert!(lines[2][2].as_str().unwrap().contains("minute 1"));
+    }
+}
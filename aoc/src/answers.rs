@@ -0,0 +1,114 @@
+use aoc_common::Answer;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// Confirmed answers of one day for one particular input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    /// SHA-256 of the input file
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Record {
+    pub fn part(&self, part: usize) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Answers file, a JSON list of records sorted by year, day and input.
+pub struct Answers {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl Answers {
+    /// Loads the answers file; a missing file is an empty registry.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let records = match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| format!("invalid answers file {}: {}", path.display(), e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(format!("cannot read {}: {}", path.display(), e)),
+        };
+        Ok(Answers {
+            path: path.to_path_buf(),
+            records,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("cannot create {}: {}", dir.display(), e))?;
+        }
+        let content = serde_json::to_string_pretty(&self.records).unwrap();
+        std::fs::write(&self.path, content + "\n")
+            .map_err(|e| format!("cannot write {}: {}", self.path.display(), e))
+    }
+
+    pub fn get(&self, year: u16, day: u8, input: &str) -> Option<&Record> {
+        self.records
+            .iter()
+            .find(|r| r.year == year && r.day == day && r.input == input)
+    }
+
+    /// Stores `answers` as confirmed, replacing an earlier record of the same input.
+    pub fn record(&mut self, year: u16, day: u8, input: &str, answers: &[Answer; 2]) {
+        let known = |answer: &Answer| (*answer != Answer::Empty).then(|| answer.to_string());
+        let record = Record {
+            year,
+            day,
+            input: input.to_string(),
+            part1: known(&answers[0]),
+            part2: known(&answers[1]),
+        };
+        self.records
+            .retain(|r| !(r.year == year && r.day == day && r.input == input));
+        self.records.push(record);
+        self.records
+            .sort_by(|a, b| (a.year, a.day, &a.input).cmp(&(b.year, b.day, &b.input)));
+    }
+}
+
+pub fn input_hash(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    #[test]
+    fn test_record_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.json", std::process::id()));
+        let hash = input_hash("1\n2\n");
+        assert_eq!(hash.len(), 64);
+
+        let mut answers = Answers::load(&path).unwrap();
+        answers.record(2022, 25, &hash, &["2=-1=0".into(), Answer::Empty]);
+        answers.record(2022, 1, &hash, &[1.into(), 2.into()]);
+        answers.record(2022, 1, &hash, &[1.into(), 3.into()]);
+        answers.save().unwrap();
+
+        let answers = Answers::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(answers.records.len(), 2);
+        assert_eq!(answers.records[0].day, 1);
+        let record = answers.get(2022, 1, &hash).unwrap();
+        assert_eq!((record.part(1), record.part(2)), (Some("1"), Some("3")));
+        assert_eq!(answers.get(2022, 25, &hash).unwrap().part2, None);
+        assert!(answers.get(2022, 1, &input_hash("other")).is_none());
+    }
+}
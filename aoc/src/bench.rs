@@ -0,0 +1,180 @@
+use crate::days::Entry;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Slowdowns smaller than this are measurement noise, whatever the threshold.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+/// Median and 95th percentile of one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    /// Nearest-rank percentiles of non-empty `samples`.
+    fn of(samples: &mut [Duration]) -> Stats {
+        samples.sort();
+        let rank = |p: usize| {
+            let i = (samples.len() * p).div_ceil(100).max(1) - 1;
+            samples[i].as_nanos() as u64
+        };
+        Stats {
+            median_ns: rank(50),
+            p95_ns: rank(95),
+        }
+    }
+
+    fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    fn p95(&self) -> Duration {
+        Duration::from_nanos(self.p95_ns)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub regressions: Vec<String>,
+}
+
+impl DayBench {
+    fn phases(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+    }
+}
+
+/// Solves `content` once to warm up and then `runs` more times.
+pub fn bench_day(entry: &Entry, content: &str, runs: usize) -> Result<DayBench, String> {
+    let mut samples = [vec![], vec![], vec![]];
+    (entry.solve)(content).map_err(|e| e.to_string())?;
+    for _ in 0..runs.max(1) {
+        let timings = (entry.solve)(content).map_err(|e| e.to_string())?.timings;
+        samples[0].push(timings.parse);
+        samples[1].push(timings.part1);
+        samples[2].push(timings.part2);
+    }
+    let [parse, part1, part2] = samples.map(|mut s| Stats::of(&mut s));
+    Ok(DayBench {
+        year: entry.year,
+        day: entry.day,
+        runs: runs.max(1),
+        parse,
+        part1,
+        part2,
+        regressions: vec![],
+    })
+}
+
+/// Flags phases whose median grew by more than `threshold` percent over `baseline`.
+pub fn compare(bench: &mut DayBench, baseline: &[DayBench], threshold: f64) {
+    let Some(base) = baseline
+        .iter()
+        .find(|b| b.year == bench.year && b.day == bench.day)
+    else {
+        return;
+    };
+    for ((name, now), (_, before)) in bench.phases().into_iter().zip(base.phases()) {
+        let (now, before) = (now.median(), before.median());
+        let limit = before.as_secs_f64() * (1.0 + threshold / 100.0);
+        if now.as_secs_f64() > limit && now.saturating_sub(before) >= NOISE_FLOOR {
+            let change = (now.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+            bench.regressions.push(format!(
+                "{} {:.1?} -> {:.1?} (+{:.0}%)",
+                name, before, now, change
+            ));
+        }
+    }
+}
+
+pub fn markdown(benches: &[DayBench], with_baseline: bool) -> String {
+    let mut table = String::from("| day | parse | part 1 | part 2 |");
+    let mut rule = String::from("|-----|------:|-------:|-------:|");
+    if with_baseline {
+        table.push_str(" regressions |");
+        rule.push_str("-------------|");
+    }
+    table.push('\n');
+    table.push_str(&rule);
+    table.push('\n');
+    for bench in benches {
+        table.push_str(&format!("| {}-{:02} |", bench.year, bench.day));
+        for (_, stats) in bench.phases() {
+            table.push_str(&format!(" {:.1?} / {:.1?} |", stats.median(), stats.p95()));
+        }
+        if with_baseline {
+            table.push_str(&format!(" {} |", bench.regressions.join(", ")));
+        }
+        table.push('\n');
+    }
+    table.push_str("\nmedian / p95 per phase\n");
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+
+    fn stats(median_ms: u64) -> Stats {
+        let ns = median_ms * 1_000_000;
+        Stats {
+            median_ns: ns,
+            p95_ns: ns,
+        }
+    }
+
+    fn day_bench(parse: u64, part1: u64, part2: u64) -> DayBench {
+        DayBench {
+            year: 2022,
+            day: 12,
+            runs: 10,
+            parse: stats(parse),
+            part1: stats(part1),
+            part2: stats(part2),
+            regressions: vec![],
+        }
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let mut samples: Vec<_> = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::of(&mut samples);
+        assert_eq!(stats.median(), Duration::from_millis(10));
+        assert_eq!(stats.p95(), Duration::from_millis(19));
+
+        let stats = Stats::of(&mut [Duration::from_millis(3)]);
+        assert_eq!((stats.median_ns, stats.p95_ns), (3_000_000, 3_000_000));
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = [day_bench(0, 100, 100)];
+        let mut bench = day_bench(0, 105, 150);
+        compare(&mut bench, &baseline, 10.0);
+        assert_eq!(bench.regressions, ["part 2 100.0ms -> 150.0ms (+50%)"]);
+
+        let mut bench = day_bench(0, 105, 150);
+        compare(&mut bench, &baseline, 60.0);
+        assert!(bench.regressions.is_empty());
+
+        let mut bench = DayBench {
+            day: 13,
+            ..day_bench(0, 500, 500)
+        };
+        compare(&mut bench, &baseline, 10.0);
+        assert!(bench.regressions.is_empty());
+    }
+}
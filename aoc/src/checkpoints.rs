@@ -0,0 +1,137 @@
+use crate::answers::input_hash;
+use crate::days::Entry;
+use aoc_common::checkpoint::Store;
+use aoc_common::warn;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Minimum time between two saves of one search.
+const EVERY: Duration = Duration::from_secs(30);
+
+/// Checkpoint file of a day, `<dir>/<year>-<day>.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Content {
+    /// SHA-256 of the input the searches run on
+    input: String,
+    states: Map<String, Value>,
+}
+
+/// Saves the checkpoints of one day for one input.
+pub struct FileStore {
+    path: PathBuf,
+    content: Content,
+    /// States of the earlier run, only kept when resuming
+    resumed: Map<String, Value>,
+}
+
+impl FileStore {
+    /// A store starting from scratch, or from the states saved by an earlier
+    /// run on the same input with `resume`.
+    pub fn open(dir: &Path, entry: &Entry, input: &str, resume: bool) -> Self {
+        let path = dir.join(format!("{}-{:02}.json", entry.year, entry.day));
+        let input = input_hash(input);
+        let mut resumed = Map::new();
+        if resume {
+            match std::fs::read_to_string(&path).map(|c| serde_json::from_str::<Content>(&c)) {
+                Ok(Ok(content)) if content.input == input => resumed = content.states,
+                Ok(Ok(_)) => warn!("not resuming, {} is for another input", path.display()),
+                Ok(Err(e)) => warn!("not resuming, invalid {}: {}", path.display(), e),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => warn!("not resuming, cannot read {}: {}", path.display(), e),
+            }
+        }
+        FileStore {
+            path,
+            content: Content {
+                input,
+                states: resumed.clone(),
+            },
+            resumed,
+        }
+    }
+
+    /// Writes a temporary file first, so an interrupted write keeps the last checkpoint.
+    fn write(&self) -> Result<(), String> {
+        if self.content.states.is_empty() {
+            return match std::fs::remove_file(&self.path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    Err(format!("cannot remove {}: {}", self.path.display(), e))
+                }
+                _ => Ok(()),
+            };
+        }
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("cannot create {}: {}", dir.display(), e))?;
+        }
+        let temp = self.path.with_extension("json.tmp");
+        let content = serde_json::to_string(&self.content).unwrap();
+        std::fs::write(&temp, content + "\n")
+            .and_then(|_| std::fs::rename(&temp, &self.path))
+            .map_err(|e| format!("cannot write {}: {}", self.path.display(), e))
+    }
+
+    pub fn install(self) {
+        aoc_common::checkpoint::set_store(Box::new(self), EVERY);
+    }
+}
+
+impl Store for FileStore {
+    fn load(&mut self, name: &str) -> Option<Value> {
+        self.resumed.remove(name)
+    }
+
+    fn save(&mut self, name: &str, state: Value) {
+        self.content.states.insert(name.to_string(), state);
+        if let Err(e) = self.write() {
+            warn!("{}", e);
+        }
+    }
+
+    fn clear(&mut self, name: &str) {
+        if self.content.states.remove(name).is_some() {
+            if let Err(e) = self.write() {
+                warn!("{}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::checkpoints::*;
+
+    #[test]
+    fn test_file_store() {
+        let dir = std::env::temp_dir().join(format!("aoc-checkpoints-{}", std::process::id()));
+        let entry = crate::days::find(2023, 20).unwrap();
+        let path = dir.join("2023-20.json");
+
+        let mut store = FileStore::open(&dir, entry, "input", true);
+        assert_eq!(store.load("presses"), None);
+        store.save("presses", Value::from(10));
+        store.save("other", Value::from(1));
+        assert!(path.exists());
+
+        // only a resumed run on the same input gets the states
+        assert_eq!(
+            FileStore::open(&dir, entry, "input", false).load("presses"),
+            None
+        );
+        assert_eq!(
+            FileStore::open(&dir, entry, "changed", true).load("presses"),
+            None
+        );
+        let mut store = FileStore::open(&dir, entry, "input", true);
+        assert_eq!(store.load("presses"), Some(Value::from(10)));
+        assert_eq!(store.load("presses"), None);
+
+        store.clear("presses");
+        assert!(path.exists());
+        store.clear("other");
+        assert!(!path.exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
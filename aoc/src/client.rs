@@ -0,0 +1,133 @@
+use std::path::Path;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/stepanmracek/aoc";
+
+/// Client of the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Session token from `AOC_SESSION`; `AOC_BASE_URL` overrides the website.
+    pub fn from_env() -> Result<Self, String> {
+        let session = std::env::var("AOC_SESSION")
+            .map_err(|_| "set AOC_SESSION to your adventofcode.com session cookie")?;
+        let base_url = std::env::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, session.trim()))
+    }
+
+    fn url(&self, year: u16, day: u8, page: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, year, day, page)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = self.url(year, day, "/input");
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|e| request_error(&url, e))?;
+        response
+            .into_string()
+            .map_err(|e| format!("cannot read response of {}: {}", url, e))
+    }
+
+    /// Posts `answer` of one part and returns the response page.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String, String> {
+        let url = self.url(year, day, "/answer");
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| request_error(&url, e))?;
+        response
+            .into_string()
+            .map_err(|e| format!("cannot read response of {}: {}", url, e))
+    }
+}
+
+fn request_error(url: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::Status(404, _) => format!("{} not found, is the puzzle unlocked yet?", url),
+        ureq::Error::Status(code, response) => {
+            let body = response.into_string().unwrap_or_default();
+            format!("{} returned {}: {}", url, code, body.trim())
+        }
+        ureq::Error::Transport(e) => format!("cannot reach {}: {}", url, e),
+    }
+}
+
+/// Reads the cached input at `path`, downloading it first if it is not there.
+///
+/// A cached input is never downloaded again.
+pub fn cached_input(
+    client: impl FnOnce() -> Result<Client, String>,
+    path: &Path,
+    year: u16,
+    day: u8,
+) -> Result<String, String> {
+    if path.exists() {
+        return std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e));
+    }
+    let input = client()?.input(year, day)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("cannot create {}: {}", dir.display(), e))?;
+    }
+    std::fs::write(path, &input).map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::*;
+    use crate::mock_server::serve;
+
+    #[test]
+    fn test_cached_input() {
+        let (base_url, requests) = serve(vec![(200, "1\n2\n3\n")]);
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = dir.join("2022").join("01.txt");
+
+        let client = || Ok(Client::new(&base_url, "secret"));
+        assert_eq!(cached_input(client, &path, 2022, 1).unwrap(), "1\n2\n3\n");
+        // the second call must not touch the server, which only answers once
+        let offline = || Err("no network".to_string());
+        assert_eq!(cached_input(offline, &path, 2022, 1).unwrap(), "1\n2\n3\n");
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let requests = requests.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/1/input "));
+        assert!(requests[0].contains("session=secret"));
+    }
+
+    #[test]
+    fn test_request_errors() {
+        let (base_url, requests) = serve(vec![(404, "Not Found"), (400, "Please log in")]);
+        let client = Client::new(&base_url, "expired");
+        let e = client.input(2030, 1).unwrap_err();
+        assert!(e.contains("is the puzzle unlocked yet?"), "{}", e);
+        let e = client.input(2022, 1).unwrap_err();
+        assert!(e.ends_with("returned 400: Please log in"), "{}", e);
+        requests.join().unwrap();
+    }
+}
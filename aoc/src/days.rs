@@ -0,0 +1,101 @@
+use aoc_common::{solve, Solver};
+use std::path::{Path, PathBuf};
+
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub solve: Solver,
+    /// Only the parser, for the fuzz tests
+    #[cfg(test)]
+    pub parse: aoc_common::Parser,
+    /// The parser and the assumption checks, for the fuzz tests
+    #[cfg(test)]
+    pub assumptions: aoc_common::Parser,
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident) => {
+        Entry {
+            year: $year,
+            day: $day,
+            solve: solve::<$krate::Day>,
+            #[cfg(test)]
+            parse: aoc_common::parse_only::<$krate::Day>,
+            #[cfg(test)]
+            assumptions: aoc_common::assumptions_only::<$krate::Day>,
+        }
+    };
+}
+
+pub const DAYS: &[Entry] = &[
+    day!(2022, 1, aoc_2022_01),
+    day!(2022, 2, aoc_2022_02),
+    day!(2022, 3, aoc_2022_03),
+    day!(2022, 4, aoc_2022_04),
+    day!(2022, 5, aoc_2022_05),
+    day!(2022, 6, aoc_2022_06),
+    day!(2022, 7, aoc_2022_07),
+    day!(2022, 8, aoc_2022_08),
+    day!(2022, 9, aoc_2022_09),
+    day!(2022, 10, aoc_2022_10),
+    day!(2022, 11, aoc_2022_11),
+    day!(2022, 12, aoc_2022_12),
+    day!(2022, 13, aoc_2022_13),
+    day!(2022, 14, aoc_2022_14),
+    day!(2022, 15, aoc_2022_15),
+    day!(2022, 16, aoc_2022_16),
+    day!(2022, 17, aoc_2022_17),
+    day!(2022, 18, aoc_2022_18),
+    day!(2022, 19, aoc_2022_19),
+    day!(2022, 20, aoc_2022_20),
+    day!(2022, 21, aoc_2022_21),
+    day!(2022, 22, aoc_2022_22),
+    day!(2022, 23, aoc_2022_23),
+    day!(2022, 24, aoc_2022_24),
+    day!(2022, 25, aoc_2022_25),
+    day!(2023, 1, aoc_2023_01),
+    day!(2023, 2, aoc_2023_02),
+    day!(2023, 3, aoc_2023_03),
+    day!(2023, 4, aoc_2023_04),
+    day!(2023, 5, aoc_2023_05),
+    day!(2023, 6, aoc_2023_06),
+    day!(2023, 7, aoc_2023_07),
+    day!(2023, 8, aoc_2023_08),
+    day!(2023, 9, aoc_2023_09),
+    day!(2023, 10, aoc_2023_10),
+    day!(2023, 11, aoc_2023_11),
+    day!(2023, 12, aoc_2023_12),
+    day!(2023, 13, aoc_2023_13),
+    day!(2023, 14, aoc_2023_14),
+    day!(2023, 15, aoc_2023_15),
+    day!(2023, 16, aoc_2023_16),
+    day!(2023, 17, aoc_2023_17),
+    day!(2023, 18, aoc_2023_18),
+    day!(2023, 19, aoc_2023_19),
+    day!(2023, 20, aoc_2023_20),
+];
+
+impl Entry {
+    /// Default input location, `<inputs>/<year>/<day>.txt`.
+    pub fn input_path(&self, inputs: &Path) -> PathBuf {
+        inputs
+            .join(self.year.to_string())
+            .join(format!("{:02}.txt", self.day))
+    }
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Entry> {
+    DAYS.iter().find(|e| e.year == year && e.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_unique_days() {
+        let keys: HashSet<_> = DAYS.iter().map(|e| (e.year, e.day)).collect();
+        assert_eq!(keys.len(), DAYS.len());
+    }
+}
@@ -0,0 +1,98 @@
+use aoc_image::{Image, Sink};
+use std::path::PathBuf;
+
+/// Collects the pictures of a run and writes the last one as PNG and/or all
+/// of them as an animated GIF.
+pub struct Exporter {
+    png: Option<PathBuf>,
+    gif: Option<PathBuf>,
+    scale: usize,
+    /// Only every n-th picture ends up in the GIF
+    every: usize,
+    delay_ms: u32,
+    pictures: usize,
+    last: Option<Image>,
+    frames: Vec<Image>,
+}
+
+impl Exporter {
+    pub fn new(
+        png: Option<PathBuf>,
+        gif: Option<PathBuf>,
+        scale: usize,
+        every: usize,
+        delay_ms: u32,
+    ) -> Self {
+        Exporter {
+            png,
+            gif,
+            scale,
+            every: every.max(1),
+            delay_ms,
+            pictures: 0,
+            last: None,
+            frames: vec![],
+        }
+    }
+}
+
+impl Sink for Exporter {
+    fn picture(&mut self, image: Image) {
+        if self.gif.is_some() && self.pictures.is_multiple_of(self.every) {
+            self.frames.push(image.clone());
+        }
+        self.pictures += 1;
+        self.last = Some(image);
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        let Some(last) = self.last.take() else {
+            return Err("the solution did not draw any pictures".into());
+        };
+        if let Some(path) = &self.png {
+            aoc_image::write_png(path, &last, self.scale)?;
+        }
+        if let Some(path) = &self.gif {
+            // the final state is always shown
+            if !(self.pictures - 1).is_multiple_of(self.every) {
+                self.frames.push(last);
+            }
+            aoc_image::write_gif(path, &self.frames, self.scale, self.delay_ms)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::*;
+    use aoc_image::{BLACK, WHITE};
+
+    #[test]
+    fn test_exporter() {
+        let dir = std::env::temp_dir();
+        let png = dir.join(format!("aoc-export-{}.png", std::process::id()));
+        let gif = dir.join(format!("aoc-export-{}.gif", std::process::id()));
+
+        let mut empty = Exporter::new(Some(png.clone()), None, 1, 1, 100);
+        assert!(empty.finish().is_err());
+
+        let mut exporter = Exporter::new(Some(png.clone()), Some(gif.clone()), 2, 3, 100);
+        for width in 1..=5 {
+            exporter.picture(Image::new(width, 1, WHITE));
+        }
+        exporter.finish().unwrap();
+
+        let last = aoc_image::read_png(&png).unwrap();
+        let frames = aoc_image::read_gif(&gif).unwrap();
+        std::fs::remove_file(&png).unwrap();
+        std::fs::remove_file(&gif).unwrap();
+
+        assert_eq!(last, Image::new(5, 1, WHITE).scaled(2));
+        // pictures 1 and 4, then the last one
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].get((0, 0)), BLACK);
+        assert_eq!(frames[0].get((0, 4)), WHITE);
+        assert_eq!(frames[2], last);
+    }
+}
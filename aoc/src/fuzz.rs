@@ -0,0 +1,178 @@
+//! Feeds every parser arbitrary text and mutated examples. A parser may
+//! reject anything, but it must neither panic nor hang, and neither may the
+//! assumption checks on whatever it accepts.
+
+use crate::days::{Entry, DAYS};
+use aoc_common::log::{self, Level};
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestRunner};
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+enum Mutation {
+    Delete(usize, usize),
+    Insert(usize, char),
+    Replace(usize, char),
+    Truncate(usize),
+    DuplicateLine(usize),
+    /// Replaces the number at or after the index with a huge one.
+    Number(usize, &'static str),
+}
+
+impl Mutation {
+    fn apply(&self, text: &mut Vec<char>) {
+        let at = |i: usize| i % (text.len() + 1);
+        match *self {
+            Mutation::Delete(i, len) => {
+                let start = at(i);
+                text.drain(start..(start + len).min(text.len()));
+            }
+            Mutation::Insert(i, c) => text.insert(at(i), c),
+            Mutation::Replace(i, c) if !text.is_empty() => {
+                let i = i % text.len();
+                text[i] = c;
+            }
+            Mutation::Replace(..) => {}
+            Mutation::Truncate(i) => text.truncate(at(i)),
+            Mutation::DuplicateLine(i) => {
+                let line: String = text.iter().collect();
+                let Some(line) = line.lines().nth(i % (line.lines().count() + 1)) else {
+                    return;
+                };
+                let line: Vec<char> = line.chars().chain(['\n']).collect();
+                text.splice(0..0, line);
+            }
+            Mutation::Number(i, number) => {
+                let Some(start) = (at(i)..text.len()).find(|&i| text[i].is_ascii_digit()) else {
+                    return;
+                };
+                let end = (start..text.len())
+                    .find(|&i| !text[i].is_ascii_digit())
+                    .unwrap_or(text.len());
+                text.splice(start..end, number.chars());
+            }
+        }
+    }
+}
+
+/// Mostly the characters puzzle inputs are made of, with a few odd ones.
+fn puzzle_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        8 => prop::char::range(' ', '~'),
+        2 => Just('\n'),
+        1 => any::<char>(),
+    ]
+}
+
+/// Numbers at the limits of the integer types, which overflow careless arithmetic.
+const HUGE_NUMBERS: &[&str] = &[
+    "2147483647",
+    "4294967295",
+    "9223372036854775807",
+    "18446744073709551615",
+    "99999999999999999999",
+];
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    let i = any::<usize>();
+    prop_oneof![
+        (i, 1..8usize).prop_map(|(i, len)| Mutation::Delete(i, len)),
+        (i, puzzle_char()).prop_map(|(i, c)| Mutation::Insert(i, c)),
+        (i, puzzle_char()).prop_map(|(i, c)| Mutation::Replace(i, c)),
+        i.prop_map(Mutation::Truncate),
+        i.prop_map(Mutation::DuplicateLine),
+        (i, prop::sample::select(HUGE_NUMBERS)).prop_map(|(i, n)| Mutation::Number(i, n)),
+    ]
+}
+
+fn mutated(example: String) -> impl Strategy<Value = String> {
+    prop::collection::vec(mutation(), 1..6).prop_map(move |mutations| {
+        let mut text: Vec<char> = example.chars().collect();
+        for m in mutations {
+            m.apply(&mut text);
+        }
+        text.into_iter().collect()
+    })
+}
+
+fn arbitrary() -> impl Strategy<Value = String> {
+    prop::collection::vec(puzzle_char(), 0..200).prop_map(|c| c.into_iter().collect())
+}
+
+fn examples(entry: &Entry) -> Vec<String> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("aoc-{}-{:02}", entry.year, entry.day));
+    let mut examples: Vec<String> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+        .map(|p| std::fs::read_to_string(p).unwrap())
+        .collect();
+    examples.sort();
+    examples
+}
+
+/// Parses in another thread to tell a panic and a hang from an error, and
+/// checks the assumptions on an input that parses.
+fn parse_within(entry: &Entry, input: &str) -> Result<(), String> {
+    let (sender, receiver) = mpsc::channel();
+    let (parse, assumptions, input) = (entry.parse, entry.assumptions, input.to_string());
+    thread::spawn(move || {
+        // false once the input parsed, true when done
+        if parse(&input).is_ok() {
+            sender.send(false).unwrap();
+            assumptions(&input).unwrap();
+        }
+        sender.send(true)
+    });
+    let mut stage = "the parser";
+    loop {
+        match receiver.recv_timeout(TIMEOUT) {
+            Ok(true) => return Ok(()),
+            Ok(false) => stage = "the assumption checks",
+            Err(RecvTimeoutError::Timeout) => return Err(format!("{} hung", stage)),
+            Err(RecvTimeoutError::Disconnected) => return Err(format!("{} panicked", stage)),
+        }
+    }
+}
+
+/// Cases for every day and kind of input, `PROPTEST_CASES` asks for more.
+fn cases() -> u32 {
+    std::env::var("PROPTEST_CASES")
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(64)
+}
+
+fn fuzz(entry: &Entry, inputs: impl Strategy<Value = String>) -> Result<(), String> {
+    let mut runner = TestRunner::new(Config {
+        cases: cases(),
+        failure_persistence: None,
+        ..Config::default()
+    });
+    runner
+        .run(&inputs, |input| {
+            parse_within(entry, &input).map_err(TestCaseError::fail)
+        })
+        .map_err(|e| format!("{} day {}: {}", entry.year, entry.day, e))
+}
+
+#[test]
+fn test_parsers() {
+    log::set_level(Level::Quiet);
+    let failures: Vec<String> = DAYS
+        .iter()
+        .flat_map(|entry| {
+            let mutated = examples(entry).into_iter().map(|e| fuzz(entry, mutated(e)));
+            std::iter::once(fuzz(entry, arbitrary())).chain(mutated)
+        })
+        .filter_map(Result::err)
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,80 @@
+//! Random valid inputs, drawn from the strategies of the days, most of them
+//! the ones that their property tests check against brute-force solvers.
+
+use crate::days::Entry;
+use proptest::strategy::{Strategy, ValueTree};
+use proptest::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
+
+/// Input of the given size for the given seed
+type Generator = fn(usize, u64) -> Result<String, String>;
+
+macro_rules! generator {
+    ($year:literal, $day:literal, $krate:ident) => {
+        ($year, $day, |size, seed| {
+            sample($krate::generate::input(size), seed)
+        })
+    };
+}
+
+/// The days with a generator; the others have inputs a random generator cannot
+/// easily keep valid, or none has been written yet.
+const GENERATORS: &[(u16, u8, Generator)] = &[
+    generator!(2022, 1, aoc_2022_01),
+    generator!(2022, 2, aoc_2022_02),
+    generator!(2022, 4, aoc_2022_04),
+    generator!(2022, 15, aoc_2022_15),
+    generator!(2022, 18, aoc_2022_18),
+    generator!(2023, 5, aoc_2023_05),
+    generator!(2023, 6, aoc_2023_06),
+    generator!(2023, 9, aoc_2023_09),
+    generator!(2023, 12, aoc_2023_12),
+    generator!(2023, 20, aoc_2023_20),
+];
+
+/// The value of `strategy` for `seed`, the same one on every call.
+fn sample(strategy: impl Strategy<Value = String>, seed: u64) -> Result<String, String> {
+    let mut bytes = [0; 32];
+    bytes[..8].copy_from_slice(&seed.to_le_bytes());
+    let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &bytes);
+    let mut runner = TestRunner::new_with_rng(Config::default(), rng);
+    strategy
+        .new_tree(&mut runner)
+        .map(|tree| tree.current())
+        .map_err(|e| format!("cannot generate an input: {}", e))
+}
+
+/// Random input of the day with up to about `size` records.
+pub fn generate(entry: &Entry, size: usize, seed: u64) -> Result<String, String> {
+    GENERATORS
+        .iter()
+        .find(|(year, day, _)| (*year, *day) == (entry.year, entry.day))
+        .map(|(_, _, generator)| generator(size, seed))
+        .unwrap_or_else(|| {
+            Err(format!(
+                "{} day {} has no input generator",
+                entry.year, entry.day
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days;
+    use crate::generate::*;
+
+    #[test]
+    fn test_generate() {
+        for &(year, day, _) in GENERATORS {
+            let entry = days::find(year, day).unwrap();
+            for size in [1, 8] {
+                let input = generate(entry, size, 7).unwrap();
+                assert_eq!(generate(entry, size, 7).unwrap(), input);
+                assert!((entry.parse)(&input).is_ok(), "{} day {}", year, day);
+            }
+        }
+        assert_eq!(
+            generate(days::find(2022, 3).unwrap(), 8, 7),
+            Err(String::from("2022 day 3 has no input generator"))
+        );
+    }
+}
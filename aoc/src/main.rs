@@ -0,0 +1,546 @@
+mod animate;
+mod answers;
+mod bench;
+mod checkpoints;
+mod client;
+mod days;
+mod export;
+#[cfg(test)]
+mod fuzz;
+mod generate;
+#[cfg(test)]
+mod mock_server;
+mod output;
+mod report;
+mod runs;
+mod scaffold;
+mod submit;
+mod verify;
+
+use animate::Player;
+use answers::{input_hash, Answers};
+use aoc_common::log::{self, Level};
+use bench::DayBench;
+use checkpoints::FileStore;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use client::Client;
+use days::Entry;
+use export::Exporter;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use submit::{Attempt, History, Verdict};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print diagnostics to stderr, -vv for dumps of every step
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    /// Do not print warnings
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all
+    Run(RunArgs),
+    /// Re-run every day on its stored input and compare with the recorded answers
+    Verify(VerifyArgs),
+    /// Time parse, part 1 and part 2 of one day, a whole year or everything
+    Bench(BenchArgs),
+    /// Turn the history of the runs into an HTML page with runtime trends and answer changes
+    Report(ReportArgs),
+    /// Download puzzle inputs into the inputs directory, skipping cached ones
+    Fetch(FetchArgs),
+    /// Submit an answer, unless earlier attempts already rule it out
+    Submit(SubmitArgs),
+    /// Create the crate of a new day and register it with the runner
+    New(NewArgs),
+    /// Print a random valid input of a day, for testing against other solutions
+    Gen(GenArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Puzzle year, e.g. 2023
+    year: Option<u16>,
+    /// Puzzle day (1-25)
+    day: Option<u8>,
+    /// Puzzle input; defaults to <INPUTS>/<YEAR>/<DAY>.txt, downloaded if missing
+    input: Option<PathBuf>,
+    /// Run every registered day
+    #[arg(long, conflicts_with_all = ["year", "day", "input"])]
+    all: bool,
+    /// Only run days of this year (with --all)
+    #[arg(long = "year", value_name = "YEAR", requires = "all")]
+    only_year: Option<u16>,
+    /// Directory with the puzzle inputs
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+    /// Reject the input on the first unparseable record instead of skipping it
+    #[arg(long)]
+    strict: bool,
+    #[arg(long, value_enum, default_value_t = RunFormat::Text)]
+    format: RunFormat,
+    /// Play the simulation frames in the terminal
+    #[arg(long)]
+    animate: bool,
+    /// Frame rate limit of the animation
+    #[arg(long, default_value_t = 30.0)]
+    fps: f64,
+    /// Record the animation as an asciicast file
+    #[arg(long, value_name = "FILE")]
+    record_cast: Option<PathBuf>,
+    /// Save the final picture of the solution as PNG
+    #[arg(long, value_name = "FILE")]
+    png: Option<PathBuf>,
+    /// Save the pictures of the solution as an animated GIF
+    #[arg(long, value_name = "FILE")]
+    gif: Option<PathBuf>,
+    /// Pixels per grid cell in the PNG and GIF
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..=64))]
+    scale: u32,
+    /// Put only every n-th picture into the GIF
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    every: u32,
+    /// Delay between the GIF frames
+    #[arg(long, value_name = "MS", default_value_t = 100)]
+    frame_ms: u32,
+    /// Record the answers as confirmed for this input
+    #[arg(long)]
+    record: bool,
+    /// File with the recorded answers
+    #[arg(long, default_value = "inputs/answers.json")]
+    answers: PathBuf,
+    /// Continue the long searches of an interrupted run from their checkpoints
+    #[arg(long)]
+    resume: bool,
+    /// Directory with the checkpoints of the long searches
+    #[arg(long, default_value = "inputs/checkpoints")]
+    checkpoints: PathBuf,
+    /// Threads for the days that spread independent work over them
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    threads: u32,
+    /// File every run appends its answers and timings to, except the
+    /// animated and exported ones, whose timings include the drawing
+    #[arg(long, default_value = "inputs/runs.jsonl")]
+    runs: PathBuf,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify days of this year
+    #[arg(long)]
+    year: Option<u16>,
+    /// Directory with the puzzle inputs
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+    /// File with the recorded answers
+    #[arg(long, default_value = "inputs/answers.json")]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark days of this year
+    year: Option<u16>,
+    /// Only benchmark this day
+    #[arg(requires = "year")]
+    day: Option<u8>,
+    /// Directory with the puzzle inputs
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+    /// Timed runs per day, after one warm-up run
+    #[arg(long, default_value_t = 10)]
+    runs: usize,
+    #[arg(long, value_enum, default_value_t = BenchFormat::Markdown)]
+    format: BenchFormat,
+    /// Compare with a baseline saved by --save
+    #[arg(long, value_name = "FILE")]
+    baseline: Option<PathBuf>,
+    /// Flag phases whose median is slower than the baseline by more than PERCENT
+    #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+    threshold: f64,
+    /// Save the results as JSON, to be used as a --baseline later
+    #[arg(long, value_name = "FILE")]
+    save: Option<PathBuf>,
+    /// Threads for the days that spread independent work over them
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    threads: u32,
+}
+
+#[derive(Args)]
+struct ReportArgs {
+    /// File with the history of the runs
+    #[arg(long, default_value = "inputs/runs.jsonl")]
+    runs: PathBuf,
+    /// Where to write the HTML page
+    #[arg(long, short, default_value = "inputs/report.html")]
+    output: PathBuf,
+    /// Flag parts whose median runtime grew after a commit by more than PERCENT
+    #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Puzzle year, e.g. 2023
+    year: u16,
+    /// Puzzle day (1-25); every registered day of the year if omitted
+    day: Option<u8>,
+    /// Directory with the puzzle inputs
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Puzzle year, e.g. 2023
+    year: u16,
+    /// Puzzle day (1-25)
+    day: u8,
+    /// Root of the workspace
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Puzzle year, e.g. 2023
+    year: u16,
+    /// Puzzle day (1-25)
+    day: u8,
+    /// Upper bound of the number of records, e.g. sensors or rows
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..=64))]
+    size: u32,
+    /// Seed of the random input; a new one, logged with -v, if omitted
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Puzzle year, e.g. 2023
+    year: u16,
+    /// Puzzle day (1-25)
+    day: u8,
+    /// Puzzle part
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    answer: String,
+    /// File with the history of submitted answers
+    #[arg(long, default_value = "inputs/submissions.json")]
+    history: PathBuf,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum RunFormat {
+    Text,
+    /// One JSON object per day with the answers, input hash and timings
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum BenchFormat {
+    Markdown,
+    Json,
+}
+
+fn read_input(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path.display(), e))
+}
+
+fn run_day(
+    entry: &Entry,
+    content: &str,
+    args: &RunArgs,
+    commit: Option<&str>,
+    record: Option<&mut Answers>,
+) -> Result<(), String> {
+    FileStore::open(&args.checkpoints, entry, content, args.resume).install();
+    let solved = (entry.solve)(content);
+    aoc_common::checkpoint::take_store();
+    let solved = solved.map_err(|e| e.to_string())?;
+    match args.format {
+        RunFormat::Text => print!("{}", output::text(entry, &solved.answers)),
+        RunFormat::Json => println!("{}", output::json(entry, content, &solved)),
+    }
+    if let Some(registry) = record {
+        registry.record(entry.year, entry.day, &input_hash(content), &solved.answers);
+    }
+    if aoc_common::anim::enabled() || aoc_image::enabled() {
+        return Ok(());
+    }
+    runs::append(&args.runs, &runs::of(entry, content, &solved, commit))
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    aoc_common::set_strict(args.strict);
+    aoc_common::parallel::set_threads(args.threads as usize);
+    if args.animate || args.record_cast.is_some() {
+        if args.animate && !std::io::stderr().is_terminal() {
+            return Err("--animate needs a terminal".into());
+        }
+        if args.fps.is_nan() || args.fps <= 0.0 {
+            return Err("--fps must be positive".into());
+        }
+        let player = Player::new(args.animate, args.fps, args.record_cast.as_deref())?;
+        aoc_common::anim::set_sink(Box::new(player));
+    }
+    if args.png.is_some() || args.gif.is_some() {
+        let exporter = Exporter::new(
+            args.png.clone(),
+            args.gif.clone(),
+            args.scale as usize,
+            args.every as usize,
+            args.frame_ms,
+        );
+        aoc_image::set_sink(Box::new(exporter));
+    }
+    let commit = runs::current_commit();
+    let mut registry = if args.record {
+        Some(Answers::load(&args.answers)?)
+    } else {
+        None
+    };
+
+    if args.all {
+        for entry in days::DAYS
+            .iter()
+            .filter(|e| args.only_year.is_none_or(|year| e.year == year))
+        {
+            let path = entry.input_path(&args.inputs);
+            if !path.exists() {
+                aoc_common::warn!(
+                    "{} day {}: no input at {}",
+                    entry.year,
+                    entry.day,
+                    path.display()
+                );
+                continue;
+            }
+            let content = read_input(&path)?;
+            run_day(entry, &content, &args, commit.as_deref(), registry.as_mut())?;
+        }
+    } else {
+        let (Some(year), Some(day)) = (args.year, args.day) else {
+            return Err("specify YEAR and DAY, or use --all".into());
+        };
+        let entry = days::find(year, day).ok_or(format!("no solution for {} day {}", year, day))?;
+        let content = match &args.input {
+            Some(path) => read_input(path)?,
+            None => {
+                client::cached_input(Client::from_env, &entry.input_path(&args.inputs), year, day)?
+            }
+        };
+        run_day(entry, &content, &args, commit.as_deref(), registry.as_mut())?;
+    }
+
+    if let Some(mut exporter) = aoc_image::take_sink() {
+        exporter.finish()?;
+    }
+    match registry {
+        Some(registry) => registry.save(),
+        None => Ok(()),
+    }
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let answers = Answers::load(&args.answers)?;
+    let days = days::DAYS
+        .iter()
+        .filter(|e| args.year.is_none_or(|year| e.year == year));
+    if verify::verify(days, &args.inputs, &answers) {
+        Ok(())
+    } else {
+        Err("some answers do not match".into())
+    }
+}
+
+fn report(args: ReportArgs) -> Result<(), String> {
+    let runs = runs::load(&args.runs)?;
+    if runs.is_empty() {
+        return Err(format!("no runs in {}", args.runs.display()));
+    }
+    let page = report::html(&runs, args.threshold);
+    std::fs::write(&args.output, page)
+        .map_err(|e| format!("cannot write {}: {}", args.output.display(), e))?;
+    println!("{}", args.output.display());
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    aoc_common::parallel::set_threads(args.threads as usize);
+    let baseline: Option<Vec<DayBench>> = match &args.baseline {
+        Some(path) => {
+            let content = std::fs::read_to_string(path)
+                .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+            Some(
+                serde_json::from_str(&content)
+                    .map_err(|e| format!("invalid baseline {}: {}", path.display(), e))?,
+            )
+        }
+        None => None,
+    };
+
+    let mut benches = vec![];
+    for entry in days::DAYS.iter().filter(|e| {
+        args.year.is_none_or(|year| e.year == year) && args.day.is_none_or(|day| e.day == day)
+    }) {
+        let path = entry.input_path(&args.inputs);
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            aoc_common::warn!(
+                "{} day {}: no input at {}",
+                entry.year,
+                entry.day,
+                path.display()
+            );
+            continue;
+        };
+        let mut day_bench = bench::bench_day(entry, &content, args.runs)?;
+        if let Some(baseline) = &baseline {
+            bench::compare(&mut day_bench, baseline, args.threshold);
+        }
+        benches.push(day_bench);
+    }
+
+    let json = serde_json::to_string_pretty(&benches).unwrap();
+    match args.format {
+        BenchFormat::Markdown => print!("{}", bench::markdown(&benches, baseline.is_some())),
+        BenchFormat::Json => println!("{}", json),
+    }
+    if let Some(path) = &args.save {
+        std::fs::write(path, json + "\n")
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+    }
+
+    let regressions = benches.iter().filter(|b| !b.regressions.is_empty()).count();
+    if regressions > 0 {
+        return Err(format!(
+            "{} days regressed by more than {}%",
+            regressions, args.threshold
+        ));
+    }
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<(), String> {
+    let days: Vec<&Entry> = days::DAYS
+        .iter()
+        .filter(|e| e.year == args.year && args.day.is_none_or(|day| e.day == day))
+        .collect();
+    if days.is_empty() {
+        return Err(match args.day {
+            Some(day) => format!("no solution for {} day {}", args.year, day),
+            None => format!("no solutions for {}", args.year),
+        });
+    }
+
+    for entry in days {
+        let path = entry.input_path(&args.inputs);
+        let cached = path.exists();
+        client::cached_input(Client::from_env, &path, entry.year, entry.day)?;
+        let status = if cached { "cached" } else { "downloaded" };
+        println!(
+            "{} day {:>2}  {:<10}  {}",
+            entry.year,
+            entry.day,
+            status,
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+fn new_day(args: NewArgs) -> Result<(), String> {
+    for path in scaffold::create(&args.root, args.year, args.day)? {
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn gen(args: GenArgs) -> Result<(), String> {
+    let entry = days::find(args.year, args.day)
+        .ok_or(format!("no solution for {} day {}", args.year, args.day))?;
+    let seed = args.seed.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64
+    });
+    aoc_common::info!("seed {}", seed);
+    let input = generate::generate(entry, args.size as usize, seed)?;
+    println!("{}", input.trim_end());
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<(), String> {
+    let mut history = History::load(&args.history)?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let answer = args.answer.trim();
+    history
+        .check(args.year, args.day, args.part, answer, now)
+        .map_err(|e| format!("not submitting {}: {}", answer, e))?;
+
+    let client = Client::from_env()?;
+    let unlocked = history.unlocked(args.year, args.day, args.part);
+    // the answer may have reached the server even if the response is unusable
+    let response = client
+        .submit(args.year, args.day, args.part, answer)
+        .and_then(|page| submit::parse_response(&page, unlocked));
+    let verdict = response.clone().unwrap_or(Verdict::Unknown);
+    history.push(Attempt {
+        year: args.year,
+        day: args.day,
+        part: args.part,
+        answer: answer.to_string(),
+        time: now,
+        verdict: verdict.clone(),
+    });
+    history.save()?;
+    response?;
+    println!(
+        "{} day {} part {}: {} is {}",
+        args.year, args.day, args.part, answer, verdict
+    );
+
+    match verdict {
+        Verdict::Correct => Ok(()),
+        _ => Err("answer not accepted".into()),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    log::set_level(match (cli.quiet, cli.verbose) {
+        (true, _) => Level::Quiet,
+        (_, 0) => Level::Warn,
+        (_, 1) => Level::Info,
+        _ => Level::Debug,
+    });
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::Report(args) => report(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new_day(args),
+        Command::Gen(args) => gen(args),
+    };
+
+    // finishes the animation and the recording
+    drop(aoc_common::anim::take_sink());
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
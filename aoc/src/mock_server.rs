@@ -0,0 +1,56 @@
+//! Local stand-in for the Advent of Code website.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::JoinHandle;
+
+/// Answers one request with each `(status, body)` in turn and then stops.
+///
+/// Returns the base URL of the server and a handle yielding the raw
+/// requests it received.
+pub fn serve(responses: Vec<(u16, &str)>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let responses: Vec<(u16, String)> = responses
+        .into_iter()
+        .map(|(status, body)| (status, body.to_string()))
+        .collect();
+
+    let handle = std::thread::spawn(move || {
+        let mut requests = vec![];
+        for (status, body) in responses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                }
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str("\r\n");
+            request.push_str(&String::from_utf8_lossy(&content));
+
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            requests.push(request);
+        }
+        requests
+    });
+    (base_url, handle)
+}
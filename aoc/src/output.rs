@@ -0,0 +1,95 @@
+use crate::answers::input_hash;
+use crate::days::Entry;
+use aoc_common::{Answer, Solved};
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Serialize)]
+struct Timings {
+    parse_ns: u64,
+    part1_ns: u64,
+    part2_ns: u64,
+    total_ns: u64,
+}
+
+/// Result of one day in the shape read by scripts.
+#[derive(Serialize)]
+struct DayResult {
+    year: u16,
+    day: u8,
+    part1: Value,
+    part2: Value,
+    /// SHA-256 of the input file
+    input: String,
+    timings: Timings,
+}
+
+/// Numbers stay numbers, an answer that is not known is null.
+fn answer_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(n) => Value::from(*n),
+        Answer::Text(s) => Value::from(s.as_str()),
+        Answer::Empty => Value::Null,
+    }
+}
+
+pub fn text(entry: &Entry, answers: &[Answer; 2]) -> String {
+    let mut text = format!("{} day {}\n", entry.year, entry.day);
+    for (part, answer) in answers.iter().enumerate() {
+        let answer = answer.to_string();
+        if answer.contains('\n') {
+            text.push_str(&format!("  part {}:\n{}\n", part + 1, answer));
+        } else {
+            text.push_str(&format!("  part {}: {}\n", part + 1, answer));
+        }
+    }
+    text
+}
+
+/// A single-line JSON object with the answers and timings of a day.
+pub fn json(entry: &Entry, content: &str, solved: &Solved) -> String {
+    let t = &solved.timings;
+    let result = DayResult {
+        year: entry.year,
+        day: entry.day,
+        part1: answer_value(&solved.answers[0]),
+        part2: answer_value(&solved.answers[1]),
+        input: input_hash(content),
+        timings: Timings {
+            parse_ns: t.parse.as_nanos() as u64,
+            part1_ns: t.part1.as_nanos() as u64,
+            part2_ns: t.part2.as_nanos() as u64,
+            total_ns: t.total().as_nanos() as u64,
+        },
+    };
+    serde_json::to_string(&result).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days;
+    use crate::output::*;
+
+    #[test]
+    fn test_json() {
+        let entry = days::find(2022, 1).unwrap();
+        let content = "1000\n2000\n\n3000\n\n4000\n";
+        let solved = (entry.solve)(content).unwrap();
+        let value: Value = serde_json::from_str(&json(entry, content, &solved)).unwrap();
+
+        assert_eq!(value["year"], 2022);
+        assert_eq!(value["day"], 1);
+        assert_eq!(value["part1"], 4000);
+        assert_eq!(value["part2"], 10000);
+        assert_eq!(value["input"], input_hash(content));
+        let timings = &value["timings"];
+        let phases: u64 = ["parse_ns", "part1_ns", "part2_ns"]
+            .iter()
+            .map(|phase| timings[phase].as_u64().unwrap())
+            .sum();
+        assert_eq!(timings["total_ns"].as_u64(), Some(phases));
+
+        assert_eq!(answer_value(&"ab".into()), "ab");
+        assert_eq!(answer_value(&Answer::Empty), Value::Null);
+    }
+}
@@ -0,0 +1,409 @@
+//! Static HTML page with the runtime trends and the answer changes found in
+//! the run history.
+
+use crate::bench::NOISE_FLOOR;
+use crate::runs::Run;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Runs of one part on one input with one pool size, in the order of the history.
+struct Series<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &'a str,
+    threads: usize,
+    runs: Vec<&'a Run>,
+}
+
+fn series(runs: &[Run]) -> Vec<Series<'_>> {
+    let mut result: Vec<Series> = vec![];
+    for run in runs {
+        let key = (run.year, run.day, run.part, run.input.as_str(), run.threads);
+        match result
+            .iter_mut()
+            .find(|s| (s.year, s.day, s.part, s.input, s.threads) == key)
+        {
+            Some(series) => series.runs.push(run),
+            None => result.push(Series {
+                year: run.year,
+                day: run.day,
+                part: run.part,
+                input: &run.input,
+                threads: run.threads,
+                runs: vec![run],
+            }),
+        }
+    }
+    result.sort_by_key(|s| (s.year, s.day, s.part, s.threads));
+    result
+}
+
+fn median(runs: &[&Run]) -> Duration {
+    let mut runtimes: Vec<Duration> = runs.iter().map(|run| run.runtime()).collect();
+    runtimes.sort();
+    runtimes[(runtimes.len() - 1) / 2]
+}
+
+/// A part that got slower with a commit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub threads: usize,
+    pub commit: Option<String>,
+    /// Median runtimes on the commit before and on this one
+    pub before: Duration,
+    pub after: Duration,
+}
+
+/// A part whose answer differs from the one of the previous run on the same input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub commit: Option<String>,
+    pub time: u64,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// Compares the median runtimes of consecutive commits of every series; a
+/// slowdown by more than `threshold` percent is a regression.
+fn series_regressions(series: &Series, threshold: f64) -> Vec<Regression> {
+    let commits: Vec<&[&Run]> = series.runs.chunk_by(|a, b| a.commit == b.commit).collect();
+    commits
+        .windows(2)
+        .filter_map(|pair| {
+            let (before, after) = (median(pair[0]), median(pair[1]));
+            let limit = before.as_secs_f64() * (1.0 + threshold / 100.0);
+            (after.as_secs_f64() > limit && after.saturating_sub(before) >= NOISE_FLOOR).then(
+                || Regression {
+                    year: series.year,
+                    day: series.day,
+                    part: series.part,
+                    threads: series.threads,
+                    commit: pair[1][0].commit.clone(),
+                    before,
+                    after,
+                },
+            )
+        })
+        .collect()
+}
+
+pub fn regressions(runs: &[Run], threshold: f64) -> Vec<Regression> {
+    series(runs)
+        .iter()
+        .flat_map(|series| series_regressions(series, threshold))
+        .collect()
+}
+
+fn series_changes(series: &Series) -> Vec<Change> {
+    series
+        .runs
+        .windows(2)
+        .filter(|pair| pair[0].answer != pair[1].answer)
+        .map(|pair| Change {
+            year: series.year,
+            day: series.day,
+            part: series.part,
+            commit: pair[1].commit.clone(),
+            time: pair[1].time,
+            before: pair[0].answer.clone(),
+            after: pair[1].answer.clone(),
+        })
+        .collect()
+}
+
+pub fn answer_changes(runs: &[Run]) -> Vec<Change> {
+    let mut changes: Vec<Change> = series(runs).iter().flat_map(series_changes).collect();
+    changes.sort_by_key(|change| std::cmp::Reverse(change.time));
+    changes
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn commit(commit: &Option<String>) -> String {
+    escape(commit.as_deref().unwrap_or("unknown"))
+}
+
+fn answer(answer: &Option<String>) -> String {
+    escape(answer.as_deref().unwrap_or("-"))
+}
+
+/// UTC date and time of `secs` since the Unix epoch.
+fn date(secs: u64) -> String {
+    // civil-from-days by Howard Hinnant, for days since 1970-01-01
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    let minutes = secs % 86_400 / 60;
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        minutes / 60,
+        minutes % 60
+    )
+}
+
+/// Runtimes of the series as a line, the first runs of regressed commits in red.
+fn sparkline(series: &Series, regressed: &[Regression]) -> String {
+    const WIDTH: f64 = 200.0;
+    const HEIGHT: f64 = 30.0;
+    let runtimes: Vec<f64> = series
+        .runs
+        .iter()
+        .map(|r| r.runtime().as_secs_f64())
+        .collect();
+    let max = runtimes.iter().cloned().fold(f64::MIN_POSITIVE, f64::max);
+    let step = WIDTH / (runtimes.len().max(2) - 1) as f64;
+    let point = |i: usize| {
+        (
+            i as f64 * step,
+            HEIGHT - runtimes[i] / max * (HEIGHT - 4.0) - 2.0,
+        )
+    };
+
+    let points: Vec<String> = (0..runtimes.len())
+        .map(|i| {
+            let (x, y) = point(i);
+            format!("{:.1},{:.1}", x, y)
+        })
+        .collect();
+    let mut svg = format!(
+        "<svg width=\"{}\" height=\"{}\"><polyline points=\"{}\"/>",
+        WIDTH,
+        HEIGHT,
+        points.join(" ")
+    );
+    for i in 1..series.runs.len() {
+        let (before, run) = (series.runs[i - 1], series.runs[i]);
+        if before.commit != run.commit && regressed.iter().any(|r| r.commit == run.commit) {
+            let (x, y) = point(i);
+            write!(svg, "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"3\"/>", x, y).unwrap();
+        }
+    }
+    svg + "</svg>"
+}
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 0.8em; text-align: left; border-bottom: 1px solid #ddd; }
+td.number { text-align: right; }
+tr.regressed { background: #fdd; }
+polyline { fill: none; stroke: #36c; stroke-width: 1.5; }
+circle { fill: #c00; }
+";
+
+/// The page of the whole history; `threshold` as in [`regressions`].
+pub fn html(runs: &[Run], threshold: f64) -> String {
+    let all_series = series(runs);
+    let mut page = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code runs</title>\n<style>\n{}</style>\n</head>\n<body>\n\
+         <h1>Advent of Code runs</h1>\n",
+        STYLE
+    );
+    if let (Some(first), Some(last)) = (runs.first(), runs.last()) {
+        writeln!(
+            page,
+            "<p>{} runs of {} parts from {} to {} UTC</p>",
+            runs.len(),
+            all_series.len(),
+            date(first.time),
+            date(last.time)
+        )
+        .unwrap();
+    }
+
+    page.push_str("<h2>Regressions</h2>\n");
+    let regressions = regressions(runs, threshold);
+    if regressions.is_empty() {
+        writeln!(
+            page,
+            "<p>No part got slower by more than {}%.</p>",
+            threshold
+        )
+        .unwrap();
+    } else {
+        page.push_str("<ul>\n");
+        for r in &regressions {
+            writeln!(
+                page,
+                "<li>{}-{:02} part {} on {} threads: {:.1?} &rarr; {:.1?} \
+                 after <code>{}</code></li>",
+                r.year,
+                r.day,
+                r.part,
+                r.threads,
+                r.before,
+                r.after,
+                commit(&r.commit)
+            )
+            .unwrap();
+        }
+        page.push_str("</ul>\n");
+    }
+
+    page.push_str("<h2>Runtimes</h2>\n<table>\n");
+    page.push_str(
+        "<tr><th>day</th><th>part</th><th>input</th><th>threads</th><th>runs</th>\
+         <th>latest</th><th>trend</th><th>answer</th></tr>\n",
+    );
+    for series in &all_series {
+        let regressed = series_regressions(series, threshold);
+        let latest = series.runs[series.runs.len() - 1];
+        let class = match regressed.is_empty() {
+            true => "",
+            false => " class=\"regressed\"",
+        };
+        writeln!(
+            page,
+            "<tr{}><td>{}-{:02}</td><td>{}</td><td><code>{}</code></td>\
+             <td class=\"number\">{}</td><td class=\"number\">{}</td><td class=\"number\">{:.1?}</td>\
+             <td>{}</td><td>{}</td></tr>",
+            class,
+            series.year,
+            series.day,
+            series.part,
+            &series.input[..series.input.len().min(8)],
+            series.threads,
+            series.runs.len(),
+            latest.runtime(),
+            sparkline(series, &regressed),
+            answer(&latest.answer)
+        )
+        .unwrap();
+    }
+    page.push_str("</table>\n");
+    writeln!(
+        page,
+        "<p>runtime = parse + part, regressions are median slowdowns over {}%</p>",
+        threshold
+    )
+    .unwrap();
+
+    page.push_str("<h2>Answer changes</h2>\n");
+    let changes = answer_changes(runs);
+    if changes.is_empty() {
+        page.push_str("<p>No answer has changed.</p>\n");
+    } else {
+        page.push_str(
+            "<table>\n<tr><th>when</th><th>day</th><th>part</th><th>commit</th>\
+             <th>before</th><th>after</th></tr>\n",
+        );
+        for change in changes {
+            writeln!(
+                page,
+                "<tr><td>{}</td><td>{}-{:02}</td><td>{}</td><td><code>{}</code></td>\
+                 <td>{}</td><td>{}</td></tr>",
+                date(change.time),
+                change.year,
+                change.day,
+                change.part,
+                commit(&change.commit),
+                answer(&change.before),
+                answer(&change.after)
+            )
+            .unwrap();
+        }
+        page.push_str("</table>\n");
+    }
+    page.push_str("</body>\n</html>\n");
+    page
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::*;
+
+    fn run(commit: &str, time: u64, answer: &str, runtime_ms: u64) -> Run {
+        Run {
+            year: 2022,
+            day: 16,
+            part: 2,
+            commit: Some(commit.to_string()),
+            input: "abc".into(),
+            answer: Some(answer.to_string()),
+            time,
+            threads: 1,
+            parse_ns: 0,
+            part_ns: runtime_ms * 1_000_000,
+        }
+    }
+
+    #[test]
+    fn test_report() {
+        let runs = [
+            run("a", 10, "1707", 100),
+            run("a", 20, "1707", 110),
+            run("b", 30, "1707", 105),
+            // the same part on a larger pool is a series of its own
+            Run {
+                threads: 4,
+                ..run("b", 35, "1707", 40)
+            },
+            run("c", 40, "1700", 300),
+            Run {
+                threads: 4,
+                ..run("c", 45, "1707", 42)
+            },
+            run("c", 50, "1707", 290),
+            Run {
+                part: 1,
+                ..run("c", 50, "1651", 1)
+            },
+        ];
+        assert_eq!(
+            regressions(&runs, 10.0),
+            [Regression {
+                year: 2022,
+                day: 16,
+                part: 2,
+                threads: 1,
+                commit: Some("c".into()),
+                before: Duration::from_millis(105),
+                after: Duration::from_millis(290),
+            }]
+        );
+        assert!(regressions(&runs, 200.0).is_empty());
+
+        let changes = answer_changes(&runs);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].time, 50);
+        assert_eq!(
+            (changes[1].before.as_deref(), changes[1].after.as_deref()),
+            (Some("1707"), Some("1700"))
+        );
+
+        assert_eq!(series(&runs).len(), 3);
+
+        let page = html(&runs, 10.0);
+        assert_eq!(page.matches("class=\"regressed\"").count(), 1);
+        assert_eq!(page.matches("<circle").count(), 1);
+        assert!(page.contains("1970-01-01 00:00"));
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(date(0), "1970-01-01 00:00");
+        assert_eq!(date(951_782_400 + 3_660), "2000-02-29 01:01");
+        assert_eq!(date(1_701_388_800), "2023-12-01 00:00");
+    }
+}
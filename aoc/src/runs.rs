@@ -0,0 +1,145 @@
+use crate::answers::input_hash;
+use crate::days::Entry;
+use aoc_common::{Answer, Solved};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+/// One part of one run, a line of the history file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Run {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Commit of the checkout, with a `-dirty` suffix for uncommitted changes
+    pub commit: Option<String>,
+    /// SHA-256 of the input file
+    pub input: String,
+    /// None for a skipped part
+    pub answer: Option<String>,
+    /// Seconds since the Unix epoch
+    pub time: u64,
+    /// Size of the pool of `--threads`
+    #[serde(default = "one_thread")]
+    pub threads: usize,
+    pub parse_ns: u64,
+    pub part_ns: u64,
+}
+
+fn one_thread() -> usize {
+    1
+}
+
+impl Run {
+    /// Time to get the answer of the part from the raw input.
+    pub fn runtime(&self) -> Duration {
+        Duration::from_nanos(self.parse_ns + self.part_ns)
+    }
+}
+
+/// The records of both parts of a solved day.
+pub fn of(entry: &Entry, content: &str, solved: &Solved, commit: Option<&str>) -> [Run; 2] {
+    let time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let t = &solved.timings;
+    [(1, t.part1), (2, t.part2)].map(|(part, duration)| Run {
+        year: entry.year,
+        day: entry.day,
+        part,
+        commit: commit.map(str::to_string),
+        input: input_hash(content),
+        answer: match &solved.answers[part as usize - 1] {
+            Answer::Empty => None,
+            answer => Some(answer.to_string()),
+        },
+        time,
+        threads: aoc_common::parallel::threads(),
+        parse_ns: t.parse.as_nanos() as u64,
+        part_ns: duration.as_nanos() as u64,
+    })
+}
+
+/// Commit checked out in the current directory, None outside of a git checkout.
+pub fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    let clean = Command::new("git")
+        .args(["diff", "--quiet", "HEAD"])
+        .status()
+        .is_ok_and(|status| status.success());
+    Some(if clean { commit } else { commit + "-dirty" })
+}
+
+/// Appends `runs` to the history, a file with one JSON object per line.
+pub fn append(path: &Path, runs: &[Run]) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("cannot create {}: {}", dir.display(), e))?;
+    }
+    let lines: String = runs
+        .iter()
+        .map(|run| serde_json::to_string(run).unwrap() + "\n")
+        .collect();
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .map_err(|e| format!("cannot write {}: {}", path.display(), e))
+}
+
+/// Loads the history in the order of the runs; a missing file is an empty history.
+pub fn load(path: &Path) -> Result<Vec<Run>, String> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("cannot read {}: {}", path.display(), e)),
+    };
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .map_err(|e| format!("invalid run at {}:{}: {}", path.display(), i + 1, e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days;
+    use crate::runs::*;
+
+    #[test]
+    fn test_history() {
+        let path = std::env::temp_dir().join(format!("aoc-runs-{}.jsonl", std::process::id()));
+        assert_eq!(load(&path), Ok(vec![]));
+
+        let entry = days::find(2022, 1).unwrap();
+        let content = "1000\n2000\n\n3000\n\n4000\n";
+        let solved = (entry.solve)(content).unwrap();
+        let runs = of(entry, content, &solved, Some("abc"));
+        append(&path, &runs).unwrap();
+        append(&path, &runs[..1]).unwrap();
+        let loaded = load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.len(), 3);
+        assert_eq!(loaded[..2], runs);
+        assert_eq!(loaded[2], runs[0]);
+        assert_eq!(runs[1].part, 2);
+        assert_eq!(runs[1].answer.as_deref(), Some("10000"));
+        assert_eq!(runs[0].commit.as_deref(), Some("abc"));
+        assert_eq!(runs[0].input, input_hash(content));
+        assert_eq!(runs[0].threads, 1);
+    }
+}
@@ -0,0 +1,210 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MANIFEST: &str = r#"[package]
+name = "{name}"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc-common.workspace = true
+"#;
+
+const SOURCE: &str = r#"use aoc_common::{Answer, ParseError, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::Empty
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Empty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::check_example;
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), Answer::Empty, Answer::Empty);
+    }
+}
+"#;
+
+fn crate_name(year: u16, day: u8) -> String {
+    format!("aoc-{}-{:02}", year, day)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+}
+
+/// Inserts `line` before the first of the lines selected by `key` that sorts
+/// after it, or after the last of them.
+fn insert_sorted<K: Ord>(content: &str, line: &str, key: impl Fn(&str) -> Option<K>) -> String {
+    let new_key = key(line).expect("the inserted line has a key");
+    let lines: Vec<&str> = content.lines().collect();
+    let keyed: Vec<usize> = (0..lines.len())
+        .filter(|&i| key(lines[i]).is_some())
+        .collect();
+    let at = keyed
+        .iter()
+        .find(|&&i| key(lines[i]).is_some_and(|k| k > new_key))
+        .copied()
+        .unwrap_or_else(|| keyed.last().map_or(lines.len(), |i| i + 1));
+    let mut result: Vec<&str> = lines[..at].to_vec();
+    result.push(line);
+    result.extend(&lines[at..]);
+    result.join("\n") + "\n"
+}
+
+/// Year and day of a `day!(2023, 5, aoc_2023_05),` line.
+fn registered_day(line: &str) -> Option<(u16, u8)> {
+    let args = line.trim().strip_prefix("day!(")?;
+    let mut args = args.split(',').map(str::trim);
+    Some((args.next()?.parse().ok()?, args.next()?.parse().ok()?))
+}
+
+/// Name of an `aoc-2023-05 = { path = "../aoc-2023-05" }` dependency.
+fn day_dependency(line: &str) -> Option<String> {
+    let (name, _) = line.split_once(" = ")?;
+    let rest = name.strip_prefix("aoc-")?;
+    rest.starts_with(|c: char| c.is_ascii_digit())
+        .then(|| name.to_string())
+}
+
+/// The workspace members with a `aoc-<year>-*` entry, if it is missing.
+fn with_member(manifest: &str, year: u16) -> Result<Option<String>, String> {
+    let member = format!("\"aoc-{}-*\"", year);
+    if manifest.contains(&member) {
+        return Ok(None);
+    }
+    let (start, line) = manifest
+        .lines()
+        .find(|line| line.starts_with("members = ["))
+        .map(|line| (manifest.find(line).unwrap(), line))
+        .ok_or("no workspace members in Cargo.toml")?;
+    let end = line
+        .rfind(']')
+        .ok_or("workspace members are not on one line")?;
+    let members = format!("{}, {}]", &line[..end], member);
+    Ok(Some(format!(
+        "{}{}{}",
+        &manifest[..start],
+        members,
+        &manifest[start + line.len()..]
+    )))
+}
+
+/// Creates the crate of a new day in the workspace at `root` and registers
+/// it with the runner. Returns the created and changed files.
+pub fn create(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {} in Advent of Code", day));
+    }
+    if year < 2015 {
+        return Err(format!("there is no Advent of Code {}", year));
+    }
+
+    let name = crate_name(year, day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let days_path = root.join("aoc/src/days.rs");
+    let days = read(&days_path)?;
+    if days
+        .lines()
+        .any(|line| registered_day(line) == Some((year, day)))
+    {
+        return Err(format!("{} day {} is already registered", year, day));
+    }
+    let runner_path = root.join("aoc/Cargo.toml");
+    let runner = read(&runner_path)?;
+    let workspace_path = root.join("Cargo.toml");
+    let workspace = read(&workspace_path)?;
+
+    // everything is checked before the first file is written
+    let days = insert_sorted(
+        &days,
+        &format!("    day!({}, {}, {}),", year, day, name.replace('-', "_")),
+        registered_day,
+    );
+    let runner = insert_sorted(
+        &runner,
+        &format!("{} = {{ path = \"../{}\" }}", name, name),
+        day_dependency,
+    );
+    let workspace = with_member(&workspace, year)?;
+
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|e| format!("cannot create {}: {}", dir.display(), e))?;
+    let mut changed = vec![
+        dir.join("Cargo.toml"),
+        dir.join("src/lib.rs"),
+        dir.join("example.txt"),
+    ];
+    write(&changed[0], &MANIFEST.replace("{name}", &name))?;
+    write(&changed[1], SOURCE)?;
+    write(&changed[2], "")?;
+    write(&days_path, &days)?;
+    write(&runner_path, &runner)?;
+    changed.extend([days_path, runner_path]);
+    if let Some(workspace) = workspace {
+        write(&workspace_path, &workspace)?;
+        changed.push(workspace_path);
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::*;
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), include_str!("../../Cargo.toml")).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), include_str!("../Cargo.toml")).unwrap();
+        fs::write(root.join("aoc/src/days.rs"), include_str!("days.rs")).unwrap();
+
+        assert!(create(&root, 2022, 26).is_err());
+        assert!(create(&root, 2023, 20).is_err());
+        assert_eq!(create(&root, 2023, 21).unwrap().len(), 5);
+        assert!(create(&root, 2023, 21).is_err());
+        // the first day of a year also adds the year to the workspace
+        assert_eq!(create(&root, 2024, 1).unwrap().len(), 6);
+
+        let days = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert!(days.contains(
+            "    day!(2023, 20, aoc_2023_20),\n    day!(2023, 21, aoc_2023_21),\n    day!(2024, 1, aoc_2024_01),\n];"
+        ));
+        let runner = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        assert!(runner.contains(
+            "aoc-2023-21 = { path = \"../aoc-2023-21\" }\naoc-2024-01 = { path = \"../aoc-2024-01\" }\n"
+        ));
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("\"aoc-2023-*\", \"aoc-2024-*\"]\n"));
+        let manifest = fs::read_to_string(root.join("aoc-2023-21/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"aoc-2023-21\""));
+        assert!(root.join("aoc-2024-01/example.txt").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,326 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+/// Outcome of one submitted answer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "verdict", rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited {
+        wait_secs: u64,
+    },
+    /// The part is solved already
+    AlreadySolved,
+    /// The part is not unlocked yet
+    Locked,
+    /// A page the verdict could not be read from
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::RateLimited { wait_secs } => {
+                write!(f, "rate limited, try again in {}s", wait_secs)
+            }
+            Verdict::AlreadySolved => write!(f, "not checked, the part is solved already"),
+            Verdict::Locked => write!(f, "not checked, the part is not unlocked yet"),
+            Verdict::Unknown => write!(f, "unknown, the response was not recognized"),
+        }
+    }
+}
+
+/// Parses a wait like `1m 5s` into seconds.
+fn parse_wait(wait: &str) -> Option<u64> {
+    wait.split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+/// Reads the verdict out of the page returned for a submitted answer.
+///
+/// The page does not tell a solved part from a locked one, both are "not the
+/// right level": the part counts as solved if it is known to be `unlocked`.
+pub fn parse_response(page: &str, unlocked: bool) -> Result<Verdict, String> {
+    if page.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else {
+            Ok(Verdict::Wrong)
+        }
+    } else if page.contains("You gave an answer too recently") {
+        let wait = page
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait))
+            .ok_or("rate limited, but the wait time is missing")?;
+        Ok(Verdict::RateLimited { wait_secs: wait })
+    } else if page.contains("You don't seem to be solving the right level") {
+        Ok(if unlocked {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Locked
+        })
+    } else {
+        Ok(Verdict::Unknown)
+    }
+}
+
+/// One submitted answer; `time` is in seconds since the Unix epoch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub time: u64,
+    #[serde(flatten)]
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted so far, a JSON list in the order of submission.
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the history file; a missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let attempts = match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| format!("invalid history file {}: {}", path.display(), e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(format!("cannot read {}: {}", path.display(), e)),
+        };
+        Ok(History {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("cannot create {}: {}", dir.display(), e))?;
+        }
+        let content = serde_json::to_string_pretty(&self.attempts).unwrap();
+        std::fs::write(&self.path, content + "\n")
+            .map_err(|e| format!("cannot write {}: {}", self.path.display(), e))
+    }
+
+    pub fn push(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Whether the part can be submitted: part 1 always, part 2 once part 1 is solved.
+    pub fn unlocked(&self, year: u16, day: u8, part: u8) -> bool {
+        part == 1
+            || self.attempts.iter().any(|a| {
+                (a.year, a.day, a.part) == (year, day, part - 1)
+                    && matches!(a.verdict, Verdict::Correct | Verdict::AlreadySolved)
+            })
+    }
+
+    /// Refuses `answer` if the history already tells it is not worth sending.
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), String> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| a.year == year && a.day == day && a.part == part);
+        let number: Option<i64> = answer.parse().ok();
+        for attempt in attempts {
+            let guess: Option<i64> = attempt.answer.parse().ok();
+            match &attempt.verdict {
+                Verdict::Correct => {
+                    return Err(format!("already solved, the answer is {}", attempt.answer))
+                }
+                Verdict::AlreadySolved => return Err(String::from("the part was already solved")),
+                Verdict::Unknown if attempt.answer == answer => {
+                    return Err(format!(
+                        "{} was sent already, with an unknown verdict",
+                        answer
+                    ))
+                }
+                Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow if attempt.answer == answer => {
+                    return Err(format!("{} is known to be {}", answer, attempt.verdict))
+                }
+                Verdict::TooHigh if number.zip(guess).is_some_and(|(n, g)| n >= g) => {
+                    return Err(format!("{} was too high already", attempt.answer))
+                }
+                Verdict::TooLow if number.zip(guess).is_some_and(|(n, g)| n <= g) => {
+                    return Err(format!("{} was too low already", attempt.answer))
+                }
+                _ => {}
+            }
+        }
+
+        let wait_until = self
+            .attempts
+            .iter()
+            .filter_map(|a| match a.verdict {
+                Verdict::RateLimited { wait_secs } => Some(a.time + wait_secs),
+                _ => None,
+            })
+            .max();
+        match wait_until {
+            Some(until) if until > now => Err(format!("rate limited for {}s more", until - now)),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::Client;
+    use crate::mock_server::serve;
+    use crate::submit::*;
+
+    fn attempt(answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            year: 2022,
+            day: 1,
+            part: 1,
+            answer: answer.into(),
+            time: 1000,
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+        let cases = [
+            (
+                "That's the right answer!  You are one gold star closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, ...",
+                Verdict::Wrong,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 5s left to wait.",
+                Verdict::RateLimited { wait_secs: 65 },
+            ),
+        ];
+        for (text, verdict) in cases {
+            assert_eq!(parse_response(&page(text), true), Ok(verdict));
+        }
+        let wrong_level =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(
+            parse_response(&wrong_level, true),
+            Ok(Verdict::AlreadySolved)
+        );
+        assert_eq!(parse_response(&wrong_level, false), Ok(Verdict::Locked));
+        assert_eq!(
+            parse_response("<html>maintenance</html>", true),
+            Ok(Verdict::Unknown)
+        );
+        assert!(parse_response(&page("You gave an answer too recently."), true).is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.json", std::process::id()));
+        let mut history = History::load(&path).unwrap();
+        history.push(attempt("100", Verdict::TooHigh));
+        history.push(attempt("20", Verdict::TooLow));
+        history.push(attempt("abc", Verdict::Wrong));
+        history.save().unwrap();
+        let history = History::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(history.check(2022, 1, 1, "50", 2000).is_ok());
+        assert!(history.check(2022, 1, 1, "100", 2000).is_err());
+        assert!(history.check(2022, 1, 1, "150", 2000).is_err());
+        assert!(history.check(2022, 1, 1, "20", 2000).is_err());
+        assert!(history.check(2022, 1, 1, "abc", 2000).is_err());
+        assert!(history.check(2022, 1, 2, "150", 2000).is_ok());
+
+        let mut history = history;
+        history.push(attempt("50", Verdict::RateLimited { wait_secs: 60 }));
+        assert_eq!(
+            history.check(2022, 1, 1, "50", 1030),
+            Err("rate limited for 30s more".into())
+        );
+        assert!(history.check(2022, 1, 1, "50", 1060).is_ok());
+        history.push(attempt("50", Verdict::Correct));
+        assert!(history.check(2022, 1, 1, "51", 2000).is_err());
+
+        history.push(Attempt {
+            part: 2,
+            ..attempt("70", Verdict::Unknown)
+        });
+        assert!(history.check(2022, 1, 2, "70", 2000).is_err());
+        assert!(history.check(2022, 1, 2, "71", 2000).is_ok());
+        history.push(Attempt {
+            part: 2,
+            ..attempt("71", Verdict::Locked)
+        });
+        assert!(history.check(2022, 1, 2, "72", 2000).is_ok());
+        history.push(Attempt {
+            part: 2,
+            ..attempt("72", Verdict::AlreadySolved)
+        });
+        assert!(history.check(2022, 1, 2, "73", 2000).is_err());
+
+        assert!(history.unlocked(2022, 1, 2));
+        assert!(!history.unlocked(2022, 2, 2));
+        assert!(history.unlocked(2022, 2, 1));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, requests) = serve(vec![(
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        )]);
+        let client = Client::new(&base_url, "secret");
+        let page = client.submit(2023, 7, 2, "1234").unwrap();
+        assert_eq!(parse_response(&page, true), Ok(Verdict::TooLow));
+
+        let requests = requests.join().unwrap();
+        assert!(requests[0].starts_with("POST /2023/day/7/answer "));
+        assert!(requests[0].contains("session=secret"));
+        assert!(requests[0].ends_with("level=2&answer=1234"));
+    }
+}
@@ -0,0 +1,155 @@
+use crate::answers::{input_hash, Answers};
+use crate::days::Entry;
+use aoc_common::Answer;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+enum Status {
+    Ok,
+    Mismatch,
+    Missing,
+    Failed,
+    NoInput,
+}
+
+struct Report {
+    status: Status,
+    elapsed: Option<Duration>,
+    details: Vec<String>,
+}
+
+fn verify_day(entry: &Entry, inputs: &Path, answers: &Answers) -> Report {
+    let path = entry.input_path(inputs);
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return Report {
+            status: Status::NoInput,
+            elapsed: None,
+            details: vec![],
+        };
+    };
+
+    let start = Instant::now();
+    let solved = match (entry.solve)(&content) {
+        Ok(solved) => solved,
+        Err(e) => {
+            return Report {
+                status: Status::Failed,
+                elapsed: Some(start.elapsed()),
+                details: vec![e.to_string()],
+            }
+        }
+    };
+    let elapsed = Some(solved.timings.total());
+
+    let record = answers.get(entry.year, entry.day, &input_hash(&content));
+    let mut mismatches = vec![];
+    let mut missing = vec![];
+    for (i, answer) in solved.answers.iter().enumerate() {
+        let part = i + 1;
+        match (record.and_then(|r| r.part(part)), answer) {
+            // a part without an answer, e.g. the second one of day 25
+            (None, Answer::Empty) => {}
+            (Some(expected), Answer::Empty) => mismatches.push(format!(
+                "part {}: expected {}, got no answer",
+                part, expected
+            )),
+            (Some(expected), answer) if expected != answer.to_string() => mismatches.push(format!(
+                "part {}: expected {}, got {}",
+                part, expected, answer
+            )),
+            (Some(_), _) => {}
+            (None, _) => missing.push(format!("part {}: no recorded answer", part)),
+        }
+    }
+
+    let status = if !mismatches.is_empty() {
+        Status::Mismatch
+    } else if !missing.is_empty() {
+        Status::Missing
+    } else {
+        Status::Ok
+    };
+    mismatches.extend(missing);
+    Report {
+        status,
+        elapsed,
+        details: mismatches,
+    }
+}
+
+/// Re-runs `days` on their stored inputs and compares with the recorded answers.
+///
+/// Returns whether every day with an input produced its recorded answers.
+pub fn verify<'a>(days: impl Iterator<Item = &'a Entry>, inputs: &Path, answers: &Answers) -> bool {
+    let (mut ok, mut mismatched, mut missing, mut failed, mut no_input) = (0, 0, 0, 0, 0);
+    for entry in days {
+        let report = verify_day(entry, inputs, answers);
+        let status = match report.status {
+            Status::Ok => {
+                ok += 1;
+                "ok"
+            }
+            Status::Mismatch => {
+                mismatched += 1;
+                "MISMATCH"
+            }
+            Status::Missing => {
+                missing += 1;
+                "missing"
+            }
+            Status::Failed => {
+                failed += 1;
+                "FAILED"
+            }
+            Status::NoInput => {
+                no_input += 1;
+                "no input"
+            }
+        };
+        let elapsed = report
+            .elapsed
+            .map(|e| format!("{:.1?}", e))
+            .unwrap_or_default();
+        println!(
+            "{} day {:>2}  {:<8}  {:>10}",
+            entry.year, entry.day, status, elapsed
+        );
+        for detail in report.details {
+            println!("    {}", detail);
+        }
+    }
+    println!(
+        "{} ok, {} mismatched, {} failed, {} with missing answers, {} without input",
+        ok, mismatched, failed, missing, no_input
+    );
+    mismatched == 0 && failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days;
+    use crate::verify::*;
+
+    #[test]
+    fn test_verify_day() {
+        let dir = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        let entry = days::find(2022, 25).unwrap();
+        let content = include_str!("../../aoc-2022-25/example.txt");
+        let path = entry.input_path(&dir);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, content).unwrap();
+        let hash = input_hash(content);
+
+        let mut answers = Answers::load(&dir.join("answers.json")).unwrap();
+        answers.record(2022, 25, &hash, &["2=-1=0".into(), Answer::Empty]);
+        let report = verify_day(entry, &dir, &answers);
+        assert!(matches!(report.status, Status::Ok));
+        assert!(report.details.is_empty());
+
+        answers.record(2022, 25, &hash, &["2=-1=0".into(), "50".into()]);
+        let report = verify_day(entry, &dir, &answers);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(report.status, Status::Mismatch));
+        assert_eq!(report.details, ["part 2: expected 50, got no answer"]);
+    }
+}